@@ -7,13 +7,17 @@ pub(crate) struct Analyzer<'src> {
   assignments: Table<'src, Assignment<'src>>,
   aliases:     Table<'src, Alias<'src, Name<'src>>>,
   sets:        Table<'src, Set<'src>>,
+  modules:     BTreeMap<&'src str, Name<'src>>,
 }
 
 impl<'src> Analyzer<'src> {
-  pub(crate) fn analyze(module: Module<'src>) -> CompilationResult<'src, Justfile> {
+  pub(crate) fn analyze(
+    module: Module<'src>,
+    modules: BTreeMap<&'src str, Justfile<'src>>,
+  ) -> CompilationResult<'src, Justfile<'src>> {
     let analyzer = Analyzer::new();
 
-    analyzer.justfile(module)
+    analyzer.justfile(module, modules)
   }
 
   pub(crate) fn new() -> Analyzer<'src> {
@@ -22,12 +26,14 @@ impl<'src> Analyzer<'src> {
       assignments: empty(),
       aliases:     empty(),
       sets:        empty(),
+      modules:     BTreeMap::new(),
     }
   }
 
   pub(crate) fn justfile(
     mut self,
     module: Module<'src>,
+    modules: BTreeMap<&'src str, Justfile<'src>>,
   ) -> CompilationResult<'src, Justfile<'src>> {
     for item in module.items {
       match item {
@@ -39,6 +45,16 @@ impl<'src> Analyzer<'src> {
           self.analyze_assignment(&assignment)?;
           self.assignments.insert(assignment);
         },
+        Item::Import { .. } => {},
+        Item::Mod { name } => {
+          if let Some(first) = self.modules.get(name.lexeme()) {
+            return Err(name.token().error(DuplicateModule {
+              module: name.lexeme(),
+              first:  first.line,
+            }));
+          }
+          self.modules.insert(name.lexeme(), name);
+        },
         Item::Recipe(recipe) => {
           self.analyze_recipe(&recipe)?;
           self.recipes.insert(recipe);
@@ -54,7 +70,7 @@ impl<'src> Analyzer<'src> {
 
     AssignmentResolver::resolve_assignments(&assignments)?;
 
-    let recipes = RecipeResolver::resolve_recipes(self.recipes, &assignments)?;
+    let recipes = RecipeResolver::resolve_recipes(self.recipes, &assignments, &modules)?;
 
     for recipe in recipes.values() {
       for parameter in &recipe.parameters {
@@ -64,6 +80,14 @@ impl<'src> Analyzer<'src> {
           }));
         }
       }
+
+      for local in &recipe.locals {
+        if assignments.contains_key(local.name.lexeme()) {
+          return Err(local.name.token().error(DuplicateVariable {
+            variable: local.name.lexeme(),
+          }));
+        }
+      }
     }
 
     let mut aliases = Table::new();
@@ -75,22 +99,100 @@ impl<'src> Analyzer<'src> {
 
     for (_, set) in self.sets {
       match set.value {
+        Setting::DotenvFilename(filename) => {
+          settings.dotenv_filename = Some(filename.cooked.into_owned());
+        },
+        Setting::DotenvLoad(dotenv_load) => {
+          settings.dotenv_load = Some(dotenv_load);
+        },
+        Setting::DotenvPath(paths) => {
+          settings.dotenv_path = paths
+            .into_iter()
+            .map(|path| PathBuf::from(path.cooked.as_ref()))
+            .collect();
+        },
+        Setting::Export(export) => {
+          settings.export = export;
+        },
+        Setting::Fallback(fallback) => {
+          settings.fallback = fallback;
+        },
+        Setting::PositionalArguments(positional_arguments) => {
+          settings.positional_arguments = positional_arguments;
+        },
         Setting::Shell(shell) => {
           assert!(settings.shell.is_none());
           settings.shell = Some(shell);
         },
+        Setting::WorkingDirectory(path) => {
+          settings.working_directory = Some(PathBuf::from(path.cooked.as_ref()));
+        },
+      }
+    }
+
+    let mut warnings = module.warnings;
+
+    // exported locals may be used by commands, so only warn about unused locals
+    // if they aren't exported
+    if !settings.export {
+      for recipe in recipes.values() {
+        warnings.extend(
+          Self::unused_locals(recipe).map(|local| Warning::UnusedRecipeLocal {
+            name: local.name.token(),
+          }),
+        );
       }
     }
 
     Ok(Justfile {
-      warnings: module.warnings,
+      warnings,
+      search: None,
       aliases,
       assignments,
+      modules,
       recipes,
       settings,
     })
   }
 
+  /// Recipe-local assignments which are not referenced by later locals,
+  /// dependency arguments, or the body. Shell commands may also start with
+  /// `let`, so these are likely to be commands that were unintentionally
+  /// parsed as assignments.
+  fn unused_locals<'recipe>(
+    recipe: &'recipe Recipe<'src>,
+  ) -> impl Iterator<Item = &'recipe Assignment<'src>> {
+    let mut used = BTreeSet::new();
+
+    let expressions = recipe
+      .locals
+      .iter()
+      .map(|local| &local.value)
+      .chain(
+        recipe
+          .dependencies
+          .iter()
+          .flat_map(|dependency| &dependency.arguments),
+      )
+      .chain(recipe.body.iter().flat_map(|line| {
+        line.fragments.iter().filter_map(|fragment| match fragment {
+          Fragment::Interpolation { expression } => Some(expression),
+          Fragment::Text { .. } => None,
+        })
+      }));
+
+    for expression in expressions {
+      for variable in expression.variables() {
+        used.insert(variable.lexeme());
+      }
+    }
+
+    recipe
+      .locals
+      .iter()
+      .filter(move |local| !used.contains(local.name.lexeme()))
+  }
+
   fn analyze_recipe(&self, recipe: &UnresolvedRecipe<'src>) -> CompilationResult<'src, ()> {
     if let Some(original) = self.recipes.get(recipe.name.lexeme()) {
       return Err(recipe.name.token().error(DuplicateRecipe {
@@ -125,6 +227,15 @@ impl<'src> Analyzer<'src> {
       }
     }
 
+    for local in &recipe.locals {
+      if parameters.contains(local.name.lexeme()) {
+        return Err(local.name.token().error(DuplicateVariable {
+          variable: local.name.lexeme(),
+        }));
+      }
+      parameters.insert(local.name.lexeme());
+    }
+
     let mut continued = false;
     for line in &recipe.body {
       if !recipe.shebang && !continued {
@@ -177,7 +288,7 @@ impl<'src> Analyzer<'src> {
   }
 
   fn resolve_alias(
-    recipes: &Table<'src, Rc<Recipe<'src>>>,
+    recipes: &Table<'src, Arc<Recipe<'src>>>,
     alias: Alias<'src, Name<'src>>,
   ) -> CompilationResult<'src, Alias<'src>> {
     let token = alias.name.token();
@@ -191,7 +302,7 @@ impl<'src> Analyzer<'src> {
 
     // Make sure the target recipe exists
     match recipes.get(alias.target.lexeme()) {
-      Some(target) => Ok(alias.resolve(Rc::clone(target))),
+      Some(target) => Ok(alias.resolve(Arc::clone(target))),
       None => Err(token.error(UnknownAliasTarget {
         alias:  alias.name.lexeme(),
         target: alias.target.lexeme(),
@@ -214,6 +325,16 @@ mod tests {
     kind: DuplicateAlias { alias: "foo", first: 0 },
   }
 
+  analysis_error! {
+    name: duplicate_module,
+    input: "mod foo\nmod foo",
+    offset: 12,
+    line: 1,
+    column: 4,
+    width: 3,
+    kind: DuplicateModule { module: "foo", first: 0 },
+  }
+
   analysis_error! {
     name: unknown_alias_target,
     input: "alias foo = bar\n",
@@ -304,6 +425,36 @@ mod tests {
     kind:   DuplicateVariable{variable: "a"},
   }
 
+  analysis_error! {
+    name:   duplicate_local,
+    input:  "a:\n let b := 'c'\n let b := 'd'",
+    offset:  22,
+    line:   2,
+    column: 5,
+    width:  1,
+    kind:   DuplicateVariable{variable: "b"},
+  }
+
+  analysis_error! {
+    name:   local_shadows_parameter,
+    input:  "a b:\n let b := 'c'",
+    offset:  10,
+    line:   1,
+    column: 5,
+    width:  1,
+    kind:   DuplicateVariable{variable: "b"},
+  }
+
+  analysis_error! {
+    name:   local_shadows_variable,
+    input:  "b := 'c'\na:\n let b := 'd'",
+    offset:  17,
+    line:   2,
+    column: 5,
+    width:  1,
+    kind:   DuplicateVariable{variable: "b"},
+  }
+
   analysis_error! {
     name:   extra_whitespace,
     input:  "a:\n blah\n  blarg",
@@ -41,6 +41,7 @@ impl<'src: 'run, 'run> AssignmentResolver<'src, 'run> {
     } else {
       let message = format!("attempted to resolve unknown assignment `{}`", name);
       let token = Token {
+        path:   Path::new(""),
         src:    "",
         offset: 0,
         line:   0,
@@ -82,27 +83,70 @@ impl<'src: 'run, 'run> AssignmentResolver<'src, 'run> {
           self.resolve_expression(a)?;
           self.resolve_expression(b)
         },
+        Thunk::BinaryPlus {
+          args: ([a, b], rest),
+          ..
+        } => {
+          self.resolve_expression(a)?;
+          self.resolve_expression(b)?;
+          for arg in rest {
+            self.resolve_expression(arg)?;
+          }
+          Ok(())
+        },
+        Thunk::Ternary {
+          args: [a, b, c], ..
+        } => {
+          self.resolve_expression(a)?;
+          self.resolve_expression(b)?;
+          self.resolve_expression(c)
+        },
       },
       Expression::Concatination { lhs, rhs } => {
         self.resolve_expression(lhs)?;
         self.resolve_expression(rhs)
       },
+      Expression::Join { lhs, rhs } => {
+        if let Some(lhs) = lhs {
+          self.resolve_expression(lhs)?;
+        }
+        self.resolve_expression(rhs)
+      },
       Expression::Conditional {
-        lhs,
-        rhs,
+        condition,
         then,
         otherwise,
-        ..
       } => {
-        self.resolve_expression(lhs)?;
-        self.resolve_expression(rhs)?;
+        self.resolve_condition(condition)?;
         self.resolve_expression(then)?;
         self.resolve_expression(otherwise)
       },
+      Expression::FormatString { fragments, .. } => {
+        for fragment in fragments {
+          if let Fragment::Interpolation { expression } = fragment {
+            self.resolve_expression(expression)?;
+          }
+        }
+        Ok(())
+      },
       Expression::StringLiteral { .. } | Expression::Backtick { .. } => Ok(()),
       Expression::Group { contents } => self.resolve_expression(contents),
     }
   }
+
+  fn resolve_condition(&mut self, condition: &Condition<'src>) -> CompilationResult<'src, ()> {
+    match condition {
+      Condition::Comparison { lhs, rhs, .. } => {
+        self.resolve_expression(lhs)?;
+        self.resolve_expression(rhs)
+      },
+      Condition::And { lhs, rhs } | Condition::Or { lhs, rhs } => {
+        self.resolve_condition(lhs)?;
+        self.resolve_condition(rhs)
+      },
+      Condition::Not { operand } => self.resolve_condition(operand),
+    }
+  }
 }
 
 #[cfg(test)]
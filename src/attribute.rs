@@ -0,0 +1,176 @@
+use crate::common::*;
+
+/// A recipe attribute, e.g. `[private]`
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, EnumDiscriminants)]
+#[strum_discriminants(name(AttributeDiscriminant))]
+#[strum_discriminants(derive(EnumString, IntoStaticStr, PartialOrd, Ord))]
+#[strum_discriminants(strum(serialize_all = "kebab_case"))]
+pub(crate) enum Attribute<'src> {
+  /// Skip the recipe if its evaluated body, parameters, and exported
+  /// environment are unchanged since it last succeeded
+  Cache,
+  /// Glob patterns matching the recipe's input files, relative to the
+  /// directory the recipe runs in
+  Inputs(Vec<StringLiteral<'src>>),
+  /// Run recipe lines, shebang scripts, and backticks in the invocation
+  /// directory, instead of the working directory
+  NoCd,
+  /// Don't print an error message if the recipe fails
+  NoExitMessage,
+  /// The recipe's output files, relative to the directory the recipe runs in.
+  /// If they all exist and are newer than the recipe's inputs, the recipe is
+  /// skipped.
+  Outputs(Vec<StringLiteral<'src>>),
+  /// Omit the recipe from `--list`, `--summary`, and `--choose`
+  Private,
+  /// Run a failed recipe line, or shebang script, again, up to the given
+  /// number of times, waiting for the given backoff before the first retry
+  /// and twice as long before each subsequent retry
+  Retry(Vec<StringLiteral<'src>>),
+  /// Kill the recipe if it runs for longer than the given duration, e.g.
+  /// `30s` or `5m`
+  Timeout(StringLiteral<'src>),
+  /// Glob patterns matching the files which `--watch` watches for changes
+  /// when running the recipe, relative to the working directory
+  Watch(Vec<StringLiteral<'src>>),
+  /// Run recipe lines, shebang scripts, and backticks in the given directory,
+  /// relative to the working directory
+  WorkingDirectory(StringLiteral<'src>),
+}
+
+impl<'src> Attribute<'src> {
+  /// Construct the attribute named `name`, given `arguments`
+  pub(crate) fn new(
+    name: Name<'src>,
+    arguments: Vec<StringLiteral<'src>>,
+  ) -> CompilationResult<'src, Attribute<'src>> {
+    let discriminant = name
+      .lexeme()
+      .parse::<AttributeDiscriminant>()
+      .map_err(|_| {
+        name.error(CompilationErrorKind::UnknownAttribute {
+          attribute: name.lexeme(),
+        })
+      })?;
+
+    let range = Self::argument_range(discriminant);
+
+    if !range.contains(&arguments.len()) {
+      return Err(
+        name.error(CompilationErrorKind::AttributeArgumentCountMismatch {
+          attribute: name.lexeme(),
+          found:     arguments.len(),
+          min:       *range.start(),
+          max:       *range.end(),
+        }),
+      );
+    }
+
+    if matches!(
+      discriminant,
+      AttributeDiscriminant::Inputs | AttributeDiscriminant::Watch
+    ) {
+      for pattern in &arguments {
+        if let Err(pattern_error) = glob::Pattern::new(&pattern.cooked) {
+          return Err(name.error(CompilationErrorKind::InvalidGlob {
+            message: pattern_error.to_string(),
+          }));
+        }
+      }
+    }
+
+    if discriminant == AttributeDiscriminant::Retry {
+      if arguments[0].cooked.parse::<u32>().is_err() {
+        return Err(name.error(CompilationErrorKind::InvalidRetry {
+          message: format!("`{}` is not a valid retry count", arguments[0].cooked),
+        }));
+      }
+
+      if let Some(backoff) = arguments.get(1) {
+        if let Err(duration_error) = humantime::parse_duration(&backoff.cooked) {
+          return Err(name.error(CompilationErrorKind::InvalidRetry {
+            message: format!("invalid backoff: {}", duration_error),
+          }));
+        }
+      }
+    }
+
+    if discriminant == AttributeDiscriminant::Timeout {
+      if let Err(duration_error) = humantime::parse_duration(&arguments[0].cooked) {
+        return Err(name.error(CompilationErrorKind::InvalidTimeout {
+          message: duration_error.to_string(),
+        }));
+      }
+    }
+
+    let mut arguments = arguments.into_iter();
+
+    Ok(match discriminant {
+      AttributeDiscriminant::Cache => Self::Cache,
+      AttributeDiscriminant::Inputs => Self::Inputs(arguments.collect()),
+      AttributeDiscriminant::NoCd => Self::NoCd,
+      AttributeDiscriminant::NoExitMessage => Self::NoExitMessage,
+      AttributeDiscriminant::Outputs => Self::Outputs(arguments.collect()),
+      AttributeDiscriminant::Private => Self::Private,
+      AttributeDiscriminant::Retry => Self::Retry(arguments.collect()),
+      AttributeDiscriminant::Timeout => Self::Timeout(arguments.next().unwrap()),
+      AttributeDiscriminant::Watch => Self::Watch(arguments.collect()),
+      AttributeDiscriminant::WorkingDirectory => Self::WorkingDirectory(arguments.next().unwrap()),
+    })
+  }
+
+  pub(crate) fn discriminant(&self) -> AttributeDiscriminant {
+    self.into()
+  }
+
+  pub(crate) fn name(&self) -> &'static str {
+    self.discriminant().into()
+  }
+
+  pub(crate) fn arguments(&self) -> &[StringLiteral<'src>] {
+    match self {
+      Self::Inputs(arguments)
+      | Self::Outputs(arguments)
+      | Self::Retry(arguments)
+      | Self::Watch(arguments) => arguments,
+      Self::Timeout(duration) => slice::from_ref(duration),
+      Self::WorkingDirectory(path) => slice::from_ref(path),
+      Self::Cache | Self::NoCd | Self::NoExitMessage | Self::Private => &[],
+    }
+  }
+
+  fn argument_range(discriminant: AttributeDiscriminant) -> RangeInclusive<usize> {
+    match discriminant {
+      AttributeDiscriminant::Cache
+      | AttributeDiscriminant::NoCd
+      | AttributeDiscriminant::NoExitMessage
+      | AttributeDiscriminant::Private => 0..=0,
+      AttributeDiscriminant::Inputs
+      | AttributeDiscriminant::Outputs
+      | AttributeDiscriminant::Watch => 1..=usize::MAX,
+      AttributeDiscriminant::Retry => 1..=2,
+      AttributeDiscriminant::Timeout | AttributeDiscriminant::WorkingDirectory => 1..=1,
+    }
+  }
+}
+
+impl<'src> Display for Attribute<'src> {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    write!(f, "{}", self.name())?;
+
+    let arguments = self.arguments();
+
+    if !arguments.is_empty() {
+      write!(f, "(")?;
+      for (i, argument) in arguments.iter().enumerate() {
+        if i > 0 {
+          write!(f, ", ")?;
+        }
+        write!(f, "{}", argument)?;
+      }
+      write!(f, ")")?;
+    }
+
+    Ok(())
+  }
+}
@@ -0,0 +1,138 @@
+use crate::common::*;
+
+use sha2::{Digest, Sha256};
+
+/// The cache entry of a `[cache]` recipe, stored in `.just/cache` next to the
+/// justfile, in a file named after the recipe's module path and name, e.g.
+/// `foo.build` for recipe `build` in submodule `foo`. Since names can't
+/// contain `.`, entries never collide, even if a module and a recipe share a
+/// name. The entry holds a hash of the recipe's evaluated body, parameters,
+/// exported environment, shell, and working directory, written after the
+/// recipe succeeds. If the hash is unchanged the next time the recipe runs,
+/// the recipe is skipped.
+pub(crate) struct Cache {
+  hash: String,
+  path: PathBuf,
+}
+
+impl Cache {
+  pub(crate) fn new<D>(
+    context: &RecipeContext,
+    recipe: &Recipe<D>,
+    dotenv: &BTreeMap<String, String>,
+    scope: &Scope,
+    lines: &[String],
+    positional: &[String],
+  ) -> Self {
+    let mut hasher = Sha256::new();
+
+    let mut update = |field: &str| {
+      hasher.update((field.len() as u64).to_le_bytes());
+      hasher.update(field);
+    };
+
+    for name in &context.module {
+      update(name);
+    }
+
+    update(recipe.name());
+
+    for line in lines {
+      update(line);
+    }
+
+    for argument in positional {
+      update(argument);
+    }
+
+    for (name, value) in Self::environment(context.settings, dotenv, scope) {
+      update(name);
+      update(value);
+    }
+
+    let (shell, arguments) = context.settings.shell(context.config);
+
+    update(shell);
+
+    for argument in arguments {
+      update(argument);
+    }
+
+    let working_directory = recipe
+      .working_directory(context.settings, context.search)
+      .unwrap_or_else(|| context.config.invocation_directory.clone());
+
+    update(&working_directory.to_string_lossy());
+
+    let directory = context
+      .search
+      .justfile
+      .parent()
+      .unwrap_or(&context.search.working_directory);
+
+    let name = context
+      .module
+      .iter()
+      .cloned()
+      .chain(iter::once(recipe.name()))
+      .collect::<Vec<&str>>()
+      .join(".");
+
+    Self {
+      hash: format!("{:x}", hasher.finalize()),
+      path: directory.join(".just").join("cache").join(name),
+    }
+  }
+
+  /// The variables that are exported to the recipe's commands, mirroring
+  /// `CommandExt::export`
+  fn environment<'a>(
+    settings: &Settings,
+    dotenv: &'a BTreeMap<String, String>,
+    scope: &'a Scope,
+  ) -> BTreeMap<&'a str, &'a str> {
+    let mut environment = dotenv
+      .iter()
+      .map(|(name, value)| (name.as_str(), value.as_str()))
+      .collect::<BTreeMap<&str, &str>>();
+
+    let mut scopes = Vec::new();
+    let mut parent = scope.parent();
+    while let Some(scope) = parent {
+      scopes.push(scope);
+      parent = scope.parent();
+    }
+
+    for scope in scopes.into_iter().rev() {
+      for binding in scope.bindings() {
+        if settings.export || binding.export {
+          environment.insert(binding.name.lexeme(), &binding.value);
+        }
+      }
+    }
+
+    environment
+  }
+
+  /// Whether the recipe last succeeded with the same hash
+  pub(crate) fn hit(&self) -> bool {
+    fs::read_to_string(&self.path)
+      .map(|hash| hash == self.hash)
+      .unwrap_or(false)
+  }
+
+  /// Record that the recipe succeeded
+  pub(crate) fn save<'src>(&self, recipe: &'src str) -> RunResult<'src, ()> {
+    let io_error = |io_error| RuntimeError::Cache {
+      recipe,
+      path: self.path.clone(),
+      io_error,
+    };
+
+    if let Some(directory) = self.path.parent() {
+      fs::create_dir_all(directory).map_err(io_error)?;
+    }
+
+    fs::write(&self.path, &self.hash).map_err(io_error)
+  }
+}
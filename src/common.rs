@@ -4,17 +4,22 @@ pub(crate) use std::{
   cmp,
   collections::{BTreeMap, BTreeSet},
   env,
-  ffi::OsString,
+  ffi::{OsStr, OsString},
   fmt::{self, Debug, Display, Formatter},
   fs,
   io::{self, Cursor, Write},
   iter::{self, FromIterator},
   ops::{Index, Range, RangeInclusive},
   path::{Path, PathBuf},
-  process::{self, Command, Stdio},
-  rc::Rc,
+  process::{self, Child, Command, ExitStatus, Stdio},
+  slice,
   str::{self, Chars},
-  sync::{Mutex, MutexGuard},
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    mpsc, Arc, Mutex, MutexGuard,
+  },
+  thread,
+  time::{Duration, Instant, SystemTime},
   usize, vec,
 };
 
@@ -23,15 +28,20 @@ pub(crate) use derivative::Derivative;
 pub(crate) use edit_distance::edit_distance;
 pub(crate) use libc::EXIT_FAILURE;
 pub(crate) use log::{info, warn};
+pub(crate) use notify::{EventKind, RecursiveMode, Watcher};
+pub(crate) use regex::Regex;
 pub(crate) use snafu::{ResultExt, Snafu};
-pub(crate) use strum::{Display, EnumString, IntoStaticStr};
+pub(crate) use strum::{Display, EnumDiscriminants, EnumString, IntoStaticStr};
+pub(crate) use typed_arena::Arena;
 pub(crate) use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 // modules
 pub(crate) use crate::{config_error, setting};
 
 // functions
-pub(crate) use crate::{default::default, empty::empty, load_dotenv::load_dotenv, output::output};
+pub(crate) use crate::{
+  default::default, empty::empty, load_dotenv::load_dotenv, output::output, unindent::unindent,
+};
 
 // traits
 pub(crate) use crate::{
@@ -41,24 +51,80 @@ pub(crate) use crate::{
 
 // structs and enums
 pub(crate) use crate::{
-  alias::Alias, analyzer::Analyzer, assignment::Assignment,
-  assignment_resolver::AssignmentResolver, binding::Binding, color::Color,
-  compilation_error::CompilationError, compilation_error_kind::CompilationErrorKind,
-  compiler::Compiler, config::Config, config_error::ConfigError, count::Count,
-  delimiter::Delimiter, dependency::Dependency, enclosure::Enclosure, evaluator::Evaluator,
-  expression::Expression, fragment::Fragment, function::Function,
-  function_context::FunctionContext, interrupt_guard::InterruptGuard,
-  interrupt_handler::InterruptHandler, item::Item, justfile::Justfile, keyword::Keyword,
-  lexer::Lexer, line::Line, list::List, load_error::LoadError, module::Module, name::Name,
-  output_error::OutputError, parameter::Parameter, parameter_kind::ParameterKind, parser::Parser,
-  platform::Platform, position::Position, positional::Positional, recipe::Recipe,
-  recipe_context::RecipeContext, recipe_resolver::RecipeResolver, runtime_error::RuntimeError,
-  scope::Scope, search::Search, search_config::SearchConfig, search_error::SearchError, set::Set,
-  setting::Setting, settings::Settings, shebang::Shebang, show_whitespace::ShowWhitespace,
-  string_literal::StringLiteral, subcommand::Subcommand, suggestion::Suggestion, table::Table,
-  thunk::Thunk, token::Token, token_kind::TokenKind, unresolved_dependency::UnresolvedDependency,
-  unresolved_recipe::UnresolvedRecipe, use_color::UseColor, variables::Variables,
-  verbosity::Verbosity, warning::Warning,
+  alias::Alias,
+  analyzer::Analyzer,
+  assignment::Assignment,
+  assignment_resolver::AssignmentResolver,
+  attribute::{Attribute, AttributeDiscriminant},
+  binding::Binding,
+  cache::Cache,
+  color::Color,
+  compilation_error::CompilationError,
+  compilation_error_kind::CompilationErrorKind,
+  compiler::Compiler,
+  condition::Condition,
+  conditional_operator::ConditionalOperator,
+  config::Config,
+  config_error::ConfigError,
+  count::Count,
+  delimiter::Delimiter,
+  dependency::Dependency,
+  enclosure::Enclosure,
+  evaluator::Evaluator,
+  expression::Expression,
+  fragment::Fragment,
+  function::Function,
+  function_context::FunctionContext,
+  interrupt_guard::InterruptGuard,
+  interrupt_handler::InterruptHandler,
+  item::Item,
+  job::Job,
+  justfile::Justfile,
+  keyword::Keyword,
+  lexer::Lexer,
+  line::Line,
+  list::List,
+  load_error::LoadError,
+  loader::Loader,
+  module::Module,
+  name::Name,
+  outcome::Outcome,
+  output_error::OutputError,
+  parameter::Parameter,
+  parameter_kind::ParameterKind,
+  parser::Parser,
+  plan::Plan,
+  platform::Platform,
+  position::Position,
+  positional::Positional,
+  recipe::Recipe,
+  recipe_context::RecipeContext,
+  recipe_resolver::RecipeResolver,
+  runtime_error::RuntimeError,
+  scope::Scope,
+  search::Search,
+  search_config::SearchConfig,
+  search_error::SearchError,
+  set::Set,
+  setting::Setting,
+  settings::Settings,
+  shebang::Shebang,
+  show_whitespace::ShowWhitespace,
+  string_kind::StringKind,
+  string_literal::StringLiteral,
+  subcommand::Subcommand,
+  suggestion::Suggestion,
+  table::Table,
+  thunk::Thunk,
+  token::Token,
+  token_kind::TokenKind,
+  unresolved_dependency::UnresolvedDependency,
+  unresolved_recipe::UnresolvedRecipe,
+  use_color::UseColor,
+  variables::Variables,
+  verbosity::Verbosity,
+  warning::Warning,
+  watch_filter::WatchFilter,
 };
 
 // type aliases
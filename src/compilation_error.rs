@@ -26,6 +26,44 @@ impl Display for CompilationError<'_> {
           recipe_line.ordinal(),
         )?;
       },
+      AttributeArgumentCountMismatch {
+        attribute,
+        found,
+        min,
+        max,
+      } => {
+        write!(
+          f,
+          "Attribute `{}` got {} {} but takes ",
+          attribute,
+          found,
+          Count("argument", found),
+        )?;
+
+        if min == max {
+          let expected = min;
+          writeln!(f, "{} {}", expected, Count("argument", expected))?;
+        } else if found < min {
+          writeln!(f, "at least {} {}", min, Count("argument", min))?;
+        } else {
+          writeln!(f, "at most {} {}", max, Count("argument", max))?;
+        }
+      },
+      CircularImport { ref circle } =>
+        if circle.len() == 2 {
+          writeln!(f, "Justfile `{}` imports itself", circle[0].display())?;
+        } else {
+          writeln!(
+            f,
+            "Justfile `{}` has circular import `{}`",
+            circle[circle.len() - 2].display(),
+            circle
+              .iter()
+              .map(|path| path.display().to_string())
+              .collect::<Vec<String>>()
+              .join(" -> ")
+          )?;
+        },
       CircularRecipeDependency { recipe, ref circle } =>
         if circle.len() == 2 {
           writeln!(f, "Recipe `{}` depends on itself", recipe)?;
@@ -62,6 +100,18 @@ impl Display for CompilationError<'_> {
         };
         writeln!(f, "`\\{}` is not a valid escape sequence", representation)?;
       },
+      InvalidGlob { ref message } => {
+        writeln!(f, "Invalid glob pattern: {}", message)?;
+      },
+      InvalidRegex { ref message } => {
+        writeln!(f, "Invalid regular expression: {}", message)?;
+      },
+      InvalidRetry { ref message } => {
+        writeln!(f, "Invalid retry: {}", message)?;
+      },
+      InvalidTimeout { ref message } => {
+        writeln!(f, "Invalid timeout: {}", message)?;
+      },
       DuplicateParameter { recipe, parameter } => {
         writeln!(
           f,
@@ -87,6 +137,24 @@ impl Display for CompilationError<'_> {
           self.token.line.ordinal(),
         )?;
       },
+      DuplicateAttribute { attribute, first } => {
+        writeln!(
+          f,
+          "Recipe attribute `{}` first used on line {} is duplicated on line {}",
+          attribute,
+          first.ordinal(),
+          self.token.line.ordinal()
+        )?;
+      },
+      DuplicateModule { module, first } => {
+        writeln!(
+          f,
+          "Module `{}` first declared on line {} is redeclared on line {}",
+          module,
+          first.ordinal(),
+          self.token.line.ordinal()
+        )?;
+      },
       DuplicateRecipe { recipe, first } => {
         writeln!(
           f,
@@ -128,10 +196,14 @@ impl Display for CompilationError<'_> {
           writeln!(f, "at most {} {}", max, Count("argument", max))?;
         }
       },
-      ExpectedKeyword { expected, found } => writeln!(
+      ExpectedKeyword {
+        ref expected,
+        found,
+      } => writeln!(
         f,
-        "Expected keyword `{}` but found identifier `{}`",
-        expected, found
+        "Expected keyword {} but found identifier `{}`",
+        List::or_ticked(expected),
+        found
       )?,
       ParameterShadowsVariable { parameter } => {
         writeln!(
@@ -147,6 +219,13 @@ impl Display for CompilationError<'_> {
           parameter
         )?;
       },
+      ModuleNotFound { module } => {
+        writeln!(
+          f,
+          "Source file for module `{}` not found, expected `{}.just` or `{}/justfile`",
+          module, module, module
+        )?;
+      },
       ParameterFollowsVariadicParameter { parameter } => {
         writeln!(f, "Parameter `{}` follows variadic parameter", parameter)?;
       },
@@ -164,15 +243,38 @@ impl Display for CompilationError<'_> {
       FunctionArgumentCountMismatch {
         function,
         found,
-        expected,
+        ref expected,
       } => {
-        writeln!(
+        write!(
           f,
-          "Function `{}` called with {} {} but takes {}",
+          "Function `{}` called with {} {} but takes ",
           function,
           found,
           Count("argument", found),
-          expected
+        )?;
+
+        if expected.start() == expected.end() {
+          writeln!(f, "{}", expected.start())?;
+        } else {
+          writeln!(f, "at least {}", expected.start())?;
+        }
+      },
+      ImportIoError {
+        ref path,
+        ref message,
+      } => {
+        writeln!(
+          f,
+          "Failed to read imported justfile at `{}`: {}",
+          path.display(),
+          message
+        )?;
+      },
+      IncompatibleAttributes { first, second } => {
+        writeln!(
+          f,
+          "Recipe attribute `{}` may not be used with attribute `{}`",
+          second, first
         )?;
       },
       InconsistentLeadingWhitespace { expected, found } => {
@@ -187,6 +289,16 @@ impl Display for CompilationError<'_> {
       UnknownAliasTarget { alias, target } => {
         writeln!(f, "Alias `{}` has an unknown target `{}`", alias, target)?;
       },
+      UnknownModule { recipe, module } => {
+        writeln!(
+          f,
+          "Recipe `{}` has dependency in unknown module `{}`",
+          recipe, module
+        )?;
+      },
+      UnknownAttribute { attribute } => {
+        writeln!(f, "Unknown attribute `{}`", attribute)?;
+      },
       UnknownDependency { recipe, unknown } => {
         writeln!(
           f,
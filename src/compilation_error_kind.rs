@@ -6,6 +6,15 @@ pub(crate) enum CompilationErrorKind<'src> {
     alias:       &'src str,
     recipe_line: usize,
   },
+  AttributeArgumentCountMismatch {
+    attribute: &'src str,
+    found:     usize,
+    min:       usize,
+    max:       usize,
+  },
+  CircularImport {
+    circle: Vec<PathBuf>,
+  },
   CircularRecipeDependency {
     recipe: &'src str,
     circle: Vec<&'src str>,
@@ -24,6 +33,14 @@ pub(crate) enum CompilationErrorKind<'src> {
     alias: &'src str,
     first: usize,
   },
+  DuplicateAttribute {
+    attribute: &'src str,
+    first:     usize,
+  },
+  DuplicateModule {
+    module: &'src str,
+    first:  usize,
+  },
   DuplicateParameter {
     recipe:    &'src str,
     parameter: &'src str,
@@ -40,14 +57,34 @@ pub(crate) enum CompilationErrorKind<'src> {
     first:   usize,
   },
   ExpectedKeyword {
-    expected: Keyword,
+    expected: Vec<Keyword>,
     found:    &'src str,
   },
   ExtraLeadingWhitespace,
   FunctionArgumentCountMismatch {
     function: &'src str,
     found:    usize,
-    expected: usize,
+    expected: RangeInclusive<usize>,
+  },
+  ImportIoError {
+    path:    PathBuf,
+    message: String,
+  },
+  InvalidGlob {
+    message: String,
+  },
+  InvalidRegex {
+    message: String,
+  },
+  InvalidRetry {
+    message: String,
+  },
+  InvalidTimeout {
+    message: String,
+  },
+  IncompatibleAttributes {
+    first:  &'src str,
+    second: &'src str,
   },
   InconsistentLeadingWhitespace {
     expected: &'src str,
@@ -62,6 +99,9 @@ pub(crate) enum CompilationErrorKind<'src> {
   MixedLeadingWhitespace {
     whitespace: &'src str,
   },
+  ModuleNotFound {
+    module: &'src str,
+  },
   ParameterFollowsVariadicParameter {
     parameter: &'src str,
   },
@@ -82,10 +122,17 @@ pub(crate) enum CompilationErrorKind<'src> {
     alias:  &'src str,
     target: &'src str,
   },
+  UnknownAttribute {
+    attribute: &'src str,
+  },
   UnknownDependency {
     recipe:  &'src str,
     unknown: &'src str,
   },
+  UnknownModule {
+    recipe: &'src str,
+    module: &'src str,
+  },
   UnknownFunction {
     function: &'src str,
   },
@@ -1,13 +1,222 @@
 use crate::common::*;
 
-pub(crate) struct Compiler;
+use CompilationErrorKind::*;
+
+/// Compiles a justfile, along with any justfiles it imports, into a single
+/// `Justfile`.
+///
+/// Imported justfiles are lexed and parsed as they are encountered, and their
+/// items are spliced into the importing module in place of the `import`
+/// statement, so the `Analyzer` sees a single module and reports duplicate
+/// definitions across files just as it would within a single file.
+///
+/// Submodules declared with `mod` are instead compiled into separate
+/// `Justfile`s, with their own namespace, which are then attached to the
+/// declaring module.
+pub(crate) struct Compiler<'src> {
+  /// Loader which owns the source text of imported justfiles
+  loader:   &'src Loader,
+  /// Directory of the root justfile, against which imported justfile paths
+  /// are displayed
+  root:     PathBuf,
+  /// Paths of justfiles currently being compiled, used to detect cycles
+  stack:    Vec<PathBuf>,
+  /// Paths of justfiles which have already been imported
+  imported: BTreeSet<PathBuf>,
+  /// Items of all compiled justfiles
+  items:    Vec<Item<'src>>,
+  /// Warnings of all compiled justfiles
+  warnings: Vec<Warning<'src>>,
+  /// Compiled submodules
+  modules:  BTreeMap<&'src str, Justfile<'src>>,
+}
+
+impl<'src> Compiler<'src> {
+  /// Compile the justfile at `path` with contents `src`
+  pub(crate) fn compile(
+    loader: &'src Loader,
+    path: &Path,
+    src: &'src str,
+  ) -> CompilationResult<'src, Justfile<'src>> {
+    let compiler = Compiler::new(
+      loader,
+      path.parent().map(Path::to_owned).unwrap_or_default(),
+      Vec::new(),
+    );
 
-impl Compiler {
-  pub(crate) fn compile(src: &str) -> CompilationResult<Justfile> {
     let tokens = Lexer::lex(src)?;
 
-    let ast = Parser::parse(&tokens)?;
+    let module = Parser::parse(&tokens)?;
+
+    compiler.finish(path, module)
+  }
+
+  fn new(loader: &'src Loader, root: PathBuf, stack: Vec<PathBuf>) -> Compiler<'src> {
+    Compiler {
+      imported: BTreeSet::new(),
+      items: Vec::new(),
+      modules: BTreeMap::new(),
+      warnings: Vec::new(),
+      loader,
+      root,
+      stack,
+    }
+  }
+
+  /// Compile `module`, found at `path`, and analyze the result
+  fn finish(
+    mut self,
+    path: &Path,
+    module: Module<'src>,
+  ) -> CompilationResult<'src, Justfile<'src>> {
+    self.compile_module(path, module)?;
+
+    Analyzer::analyze(
+      Module {
+        items:    self.items,
+        warnings: self.warnings,
+      },
+      self.modules,
+    )
+  }
+
+  /// Compile `src` without resolving imports, for use in tests
+  #[cfg(test)]
+  pub(crate) fn test_compile(src: &str) -> CompilationResult<Justfile> {
+    let tokens = Lexer::lex(src)?;
+
+    let module = Parser::parse(&tokens)?;
+
+    Analyzer::analyze(module, BTreeMap::new())
+  }
+
+  fn compile_module(&mut self, path: &Path, module: Module<'src>) -> CompilationResult<'src, ()> {
+    self.stack.push(path.to_owned());
+
+    self.warnings.extend(module.warnings);
+
+    for item in module.items {
+      if let Item::Mod { name } = &item {
+        if !self.modules.contains_key(name.lexeme()) {
+          let justfile = self.compile_submodule(path, *name)?;
+          self.modules.insert(name.lexeme(), justfile);
+        }
+
+        self.items.push(item);
+      } else if let Item::Import { relative, token } = &item {
+        let directory = path.parent().unwrap_or_else(|| Path::new(""));
+
+        let import = Search::clean(directory, Path::new(relative.cooked.as_ref()));
+
+        if self.stack.contains(&import) {
+          let circle = self
+            .stack
+            .iter()
+            .skip_while(|path| **path != import)
+            .chain(iter::once(&import))
+            .map(|path| self.display(path))
+            .collect();
+
+          return Err(token.error(CircularImport { circle }));
+        }
+
+        if !self.imported.insert(import.clone()) {
+          continue;
+        }
+
+        let src = self.loader.load(&import).map_err(|load_error| {
+          token.error(ImportIoError {
+            path:    self.display(&import),
+            message: load_error.io_error.to_string(),
+          })
+        })?;
+
+        let display = self.loader.path(self.display(&import));
+
+        let tokens = Lexer::lex_import(display, src)?;
+
+        let module = Parser::parse(&tokens)?;
+
+        self.items.push(item);
+
+        self.compile_module(&import, module)?;
+      } else {
+        self.items.push(item);
+      }
+    }
+
+    self.stack.pop();
+
+    Ok(())
+  }
+
+  /// Compile the submodule `name`, declared in the justfile at `path`, from
+  /// either `name.just` or `name/justfile`
+  fn compile_submodule(
+    &mut self,
+    path: &Path,
+    name: Name<'src>,
+  ) -> CompilationResult<'src, Justfile<'src>> {
+    let directory = path.parent().unwrap_or_else(|| Path::new(""));
+
+    let source = [
+      directory.join(format!("{}.just", name.lexeme())),
+      directory.join(name.lexeme()).join(crate::search::FILENAME),
+    ]
+    .iter()
+    .find(|candidate| candidate.is_file())
+    .cloned()
+    .ok_or_else(|| {
+      name.error(ModuleNotFound {
+        module: name.lexeme(),
+      })
+    })?;
+
+    if self.stack.contains(&source) {
+      let circle = self
+        .stack
+        .iter()
+        .skip_while(|path| **path != source)
+        .chain(iter::once(&source))
+        .map(|path| self.display(path))
+        .collect();
+
+      return Err(name.error(CircularImport { circle }));
+    }
+
+    let src = self.loader.load(&source).map_err(|load_error| {
+      name.error(ImportIoError {
+        path:    self.display(&source),
+        message: load_error.io_error.to_string(),
+      })
+    })?;
+
+    let display = self.loader.path(self.display(&source));
+
+    let tokens = Lexer::lex_import(display, src)?;
+
+    let module = Parser::parse(&tokens)?;
+
+    let compiler = Compiler::new(self.loader, self.root.clone(), self.stack.clone());
+
+    let mut justfile = compiler.finish(&source, module)?;
+
+    self.warnings.append(&mut justfile.warnings);
+
+    justfile.search = Some(Search {
+      working_directory: source.parent().map(Path::to_owned).unwrap_or_default(),
+      justfile:          source,
+    });
+
+    Ok(justfile)
+  }
 
-    Analyzer::analyze(ast)
+  /// The path of the justfile at `path`, relative to the root justfile's
+  /// directory if possible
+  fn display(&self, path: &Path) -> PathBuf {
+    path
+      .strip_prefix(&self.root)
+      .map(Path::to_owned)
+      .unwrap_or_else(|_| path.to_owned())
   }
 }
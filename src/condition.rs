@@ -0,0 +1,71 @@
+use crate::common::*;
+
+/// The condition of a conditional expression, e.g. `a == b && !(c =~ d)`
+#[derive(PartialEq, Debug)]
+pub(crate) enum Condition<'src> {
+  /// `lhs == rhs`, `lhs != rhs`, or `lhs =~ rhs`
+  Comparison {
+    lhs:      Box<Expression<'src>>,
+    rhs:      Box<Expression<'src>>,
+    operator: ConditionalOperator,
+  },
+  /// `lhs && rhs`
+  And {
+    lhs: Box<Condition<'src>>,
+    rhs: Box<Condition<'src>>,
+  },
+  /// `lhs || rhs`
+  Or {
+    lhs: Box<Condition<'src>>,
+    rhs: Box<Condition<'src>>,
+  },
+  /// `!operand`
+  Not { operand: Box<Condition<'src>> },
+}
+
+impl<'src> Condition<'src> {
+  /// Write `condition`, wrapping it in parentheses if it binds more loosely
+  /// than `self`
+  fn fmt_operand(&self, f: &mut Formatter, condition: &Condition) -> fmt::Result {
+    if condition.precedence() < self.precedence() {
+      write!(f, "({})", condition)
+    } else {
+      write!(f, "{}", condition)
+    }
+  }
+
+  fn precedence(&self) -> u8 {
+    match self {
+      Self::Or { .. } => 0,
+      Self::And { .. } => 1,
+      Self::Not { .. } => 2,
+      Self::Comparison { .. } => 3,
+    }
+  }
+}
+
+impl<'src> Display for Condition<'src> {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    match self {
+      Self::Comparison { lhs, rhs, operator } => write!(f, "{} {} {}", lhs, operator, rhs),
+      Self::And { lhs, rhs } => {
+        self.fmt_operand(f, lhs)?;
+        write!(f, " && ")?;
+        self.fmt_operand(f, rhs)
+      },
+      Self::Or { lhs, rhs } => {
+        self.fmt_operand(f, lhs)?;
+        write!(f, " || ")?;
+        self.fmt_operand(f, rhs)
+      },
+      Self::Not { operand } => {
+        write!(f, "!")?;
+        if let Self::Comparison { .. } = **operand {
+          write!(f, "({})", operand)
+        } else {
+          self.fmt_operand(f, operand)
+        }
+      },
+    }
+  }
+}
@@ -19,12 +19,18 @@ pub(crate) struct Config {
   pub(crate) dry_run:              bool,
   pub(crate) highlight:            bool,
   pub(crate) invocation_directory: PathBuf,
-  pub(crate) load_dotenv:          bool,
+  pub(crate) jobs:                 usize,
+  pub(crate) keep_going:           bool,
+  pub(crate) no_cache:             bool,
+  pub(crate) dotenv_filename:      Option<String>,
+  pub(crate) dotenv_load:          Option<bool>,
+  pub(crate) dotenv_path:          Vec<PathBuf>,
   pub(crate) search_config:        SearchConfig,
   pub(crate) shell:                String,
   pub(crate) shell_args:           Vec<String>,
   pub(crate) shell_present:        bool,
   pub(crate) subcommand:           Subcommand,
+  pub(crate) timeout:              Option<Duration>,
   pub(crate) unsorted:             bool,
   pub(crate) verbosity:            Verbosity,
 }
@@ -40,6 +46,7 @@ mod cmd {
   pub(crate) const SHOW: &str = "SHOW";
   pub(crate) const SUMMARY: &str = "SUMMARY";
   pub(crate) const VARIABLES: &str = "VARIABLES";
+  pub(crate) const WATCH: &str = "WATCH";
 
   pub(crate) const ALL: &[&str] = &[
     CHOOSE,
@@ -52,6 +59,7 @@ mod cmd {
     SHOW,
     SUMMARY,
     VARIABLES,
+    WATCH,
   ];
 
   pub(crate) const ARGLESS: &[&str] = &[
@@ -71,15 +79,22 @@ mod arg {
   pub(crate) const CHOOSER: &str = "CHOOSER";
   pub(crate) const CLEAR_SHELL_ARGS: &str = "CLEAR-SHELL-ARGS";
   pub(crate) const COLOR: &str = "COLOR";
+  pub(crate) const DOTENV_FILENAME: &str = "DOTENV-FILENAME";
+  pub(crate) const DOTENV_LOAD: &str = "DOTENV-LOAD";
+  pub(crate) const DOTENV_PATH: &str = "DOTENV-PATH";
   pub(crate) const DRY_RUN: &str = "DRY-RUN";
   pub(crate) const HIGHLIGHT: &str = "HIGHLIGHT";
+  pub(crate) const JOBS: &str = "JOBS";
   pub(crate) const JUSTFILE: &str = "JUSTFILE";
+  pub(crate) const KEEP_GOING: &str = "KEEP-GOING";
+  pub(crate) const NO_CACHE: &str = "NO-CACHE";
   pub(crate) const NO_DOTENV: &str = "NO-DOTENV";
   pub(crate) const NO_HIGHLIGHT: &str = "NO-HIGHLIGHT";
   pub(crate) const QUIET: &str = "QUIET";
   pub(crate) const SET: &str = "SET";
   pub(crate) const SHELL: &str = "SHELL";
   pub(crate) const SHELL_ARG: &str = "SHELL-ARG";
+  pub(crate) const TIMEOUT: &str = "TIMEOUT";
   pub(crate) const UNSORTED: &str = "UNSORTED";
   pub(crate) const VERBOSE: &str = "VERBOSE";
   pub(crate) const WORKING_DIRECTORY: &str = "WORKING-DIRECTORY";
@@ -111,6 +126,27 @@ impl Config {
           .default_value(arg::COLOR_AUTO)
           .help("Print colorful output"),
       )
+      .arg(
+        Arg::with_name(arg::DOTENV_FILENAME)
+          .long("dotenv-filename")
+          .takes_value(true)
+          .help("Search for environment file named <DOTENV-FILENAME> instead of `.env`")
+          .conflicts_with(arg::DOTENV_PATH),
+      )
+      .arg(
+        Arg::with_name(arg::DOTENV_LOAD)
+          .long("dotenv-load")
+          .help("Load environment file, even if disabled with `set dotenv-load := false`")
+          .overrides_with(arg::NO_DOTENV),
+      )
+      .arg(
+        Arg::with_name(arg::DOTENV_PATH)
+          .long("dotenv-path")
+          .takes_value(true)
+          .multiple(true)
+          .number_of_values(1)
+          .help("Load environment file at <DOTENV-PATH> instead of searching for `.env`"),
+      )
       .arg(
         Arg::with_name(arg::DRY_RUN)
           .long("dry-run")
@@ -123,10 +159,21 @@ impl Config {
           .help("Highlight echoed recipe lines in bold")
           .overrides_with(arg::NO_HIGHLIGHT),
       )
+      .arg(
+        Arg::with_name(arg::KEEP_GOING)
+          .long("keep-going")
+          .help("Keep running recipes after a recipe fails, skipping those that depend on it"),
+      )
+      .arg(
+        Arg::with_name(arg::NO_CACHE)
+          .long("no-cache")
+          .help("Run `[cache]` recipes even if they are cached"),
+      )
       .arg(
         Arg::with_name(arg::NO_DOTENV)
           .long("no-dotenv")
-          .help("Don't load `.env` file"),
+          .help("Don't load `.env` file")
+          .overrides_with(arg::DOTENV_LOAD),
       )
       .arg(
         Arg::with_name(arg::NO_HIGHLIGHT)
@@ -141,6 +188,18 @@ impl Config {
           .takes_value(true)
           .help("Use <JUSTFILE> as justfile."),
       )
+      .arg(
+        Arg::with_name(arg::JOBS)
+          .short("j")
+          .long("jobs")
+          .takes_value(true)
+          .default_value("1")
+          .validator(|value| match value.parse::<usize>() {
+            Ok(jobs) if jobs > 0 => Ok(()),
+            _ => Err(format!("`{}` is not a positive integer", value)),
+          })
+          .help("Run up to <JOBS> recipes concurrently"),
+      )
       .arg(
         Arg::with_name(arg::QUIET)
           .short("q")
@@ -181,6 +240,17 @@ impl Config {
           .overrides_with(arg::SHELL_ARG)
           .help("Clear shell arguments"),
       )
+      .arg(
+        Arg::with_name(arg::TIMEOUT)
+          .long("timeout")
+          .takes_value(true)
+          .validator(|value| {
+            humantime::parse_duration(&value)
+              .map(|_| ())
+              .map_err(|duration_error| duration_error.to_string())
+          })
+          .help("Kill recipes that run longer than <TIMEOUT>, e.g. `30s` or `5m`"),
+      )
       .arg(
         Arg::with_name(arg::UNSORTED)
           .long("unsorted")
@@ -262,6 +332,11 @@ impl Config {
           .long("variables")
           .help("List names of variables"),
       )
+      .arg(
+        Arg::with_name(cmd::WATCH)
+          .long("watch")
+          .help("Run recipes, and run them again whenever files change"),
+      )
       .group(ArgGroup::with_name("SUBCOMMAND").args(cmd::ALL));
 
     if cfg!(feature = "help4help2man") {
@@ -293,6 +368,21 @@ impl Config {
     }
   }
 
+  fn jobs_from_value(value: &str) -> ConfigResult<usize> {
+    match value.parse::<usize>() {
+      Ok(jobs) if jobs > 0 => Ok(jobs),
+      _ => Err(ConfigError::Internal {
+        message: format!("Invalid argument `{}` to --jobs.", value),
+      }),
+    }
+  }
+
+  fn timeout_from_value(value: &str) -> ConfigResult<Duration> {
+    humantime::parse_duration(value).map_err(|_| ConfigError::Internal {
+      message: format!("Invalid argument `{}` to --timeout.", value),
+    })
+  }
+
   pub(crate) fn from_matches(matches: &ArgMatches) -> ConfigResult<Self> {
     let invocation_directory = env::current_dir().context(config_error::CurrentDir)?;
 
@@ -412,6 +502,11 @@ impl Config {
       Subcommand::Evaluate { overrides }
     } else if matches.is_present(cmd::VARIABLES) {
       Subcommand::Variables
+    } else if matches.is_present(cmd::WATCH) {
+      Subcommand::Watch {
+        arguments: positional.arguments,
+        overrides,
+      }
     } else {
       Subcommand::Run {
         arguments: positional.arguments,
@@ -433,11 +528,31 @@ impl Config {
       || matches.occurrences_of(arg::SHELL) > 0
       || matches.occurrences_of(arg::SHELL_ARG) > 0;
 
+    let dotenv_load = if matches.is_present(arg::NO_DOTENV) {
+      Some(false)
+    } else if matches.is_present(arg::DOTENV_LOAD) {
+      Some(true)
+    } else {
+      None
+    };
+
     Ok(Self {
       dry_run: matches.is_present(arg::DRY_RUN),
       highlight: !matches.is_present(arg::NO_HIGHLIGHT),
+      jobs: Self::jobs_from_value(matches.value_of(arg::JOBS).unwrap())?,
+      keep_going: matches.is_present(arg::KEEP_GOING),
+      no_cache: matches.is_present(arg::NO_CACHE),
+      timeout: matches
+        .value_of(arg::TIMEOUT)
+        .map(Self::timeout_from_value)
+        .transpose()?,
       shell: matches.value_of(arg::SHELL).unwrap().to_owned(),
-      load_dotenv: !matches.is_present(arg::NO_DOTENV),
+      dotenv_filename: matches.value_of(arg::DOTENV_FILENAME).map(str::to_owned),
+      dotenv_load,
+      dotenv_path: matches
+        .values_of_os(arg::DOTENV_PATH)
+        .map(|paths| paths.map(PathBuf::from).collect())
+        .unwrap_or_default(),
       unsorted: matches.is_present(arg::UNSORTED),
       color,
       invocation_directory,
@@ -467,22 +582,21 @@ impl Config {
       return Self::edit(&search);
     }
 
-    let src = fs::read_to_string(&search.justfile)
-      .map_err(|io_error| LoadError {
-        io_error,
-        path: &search.justfile,
-      })
-      .eprint(self.color)?;
+    if let Watch {
+      arguments,
+      overrides,
+    } = &self.subcommand
+    {
+      return self.watch(&search, overrides, arguments);
+    }
 
-    let justfile = Compiler::compile(&src).eprint(self.color)?;
+    let loader = Loader::new();
 
-    for warning in &justfile.warnings {
-      if self.color.stderr().active() {
-        eprintln!("{:#}", warning);
-      } else {
-        eprintln!("{}", warning);
-      }
-    }
+    let src = loader.load(&search.justfile).eprint(self.color)?;
+
+    let justfile = Compiler::compile(&loader, &search.justfile, src).eprint(self.color)?;
+
+    self.print_warnings(&justfile);
 
     match &self.subcommand {
       Choose { overrides, chooser } =>
@@ -497,7 +611,7 @@ impl Config {
       Show { ref name } => Self::show(&name, justfile),
       Summary => self.summary(justfile),
       Variables => Self::variables(justfile),
-      Completions { .. } | Edit | Init => unreachable!(),
+      Completions { .. } | Edit | Init | Watch { .. } => unreachable!(),
     }
   }
 
@@ -508,12 +622,10 @@ impl Config {
     overrides: &BTreeMap<String, String>,
     chooser: Option<&str>,
   ) -> Result<(), i32> {
-    let recipes = justfile
-      .public_recipes(self.unsorted)
-      .iter()
-      .filter(|recipe| recipe.min_arguments() == 0)
-      .cloned()
-      .collect::<Vec<&Recipe<Dependency>>>();
+    let recipes = Self::public_recipes(&justfile, self.unsorted)
+      .into_iter()
+      .filter(|(_name, recipe)| recipe.min_arguments() == 0)
+      .collect::<Vec<(String, &Recipe<Dependency>)>>();
 
     if recipes.is_empty() {
       eprintln!("Justfile contains no choosable recipes.");
@@ -546,12 +658,12 @@ impl Config {
       },
     };
 
-    for recipe in recipes {
+    for (name, _recipe) in recipes {
       if let Err(error) = child
         .stdin
         .as_mut()
         .expect("Child was created with piped stdio")
-        .write_all(format!("{}\n", recipe.name).as_bytes())
+        .write_all(format!("{}\n", name).as_bytes())
       {
         eprintln!(
           "Failed to write to chooser `{}`: {}",
@@ -594,6 +706,20 @@ impl Config {
     self.run(justfile, search, overrides, &recipes)
   }
 
+  /// Public recipes of `justfile` followed by those of its submodules, along
+  /// with their qualified names
+  fn public_recipes<'a>(
+    justfile: &'a Justfile,
+    source_order: bool,
+  ) -> Vec<(String, &'a Recipe<'a, Dependency<'a>>)> {
+    justfile
+      .public_recipes(source_order)
+      .into_iter()
+      .map(|recipe| (recipe.name().to_owned(), recipe))
+      .chain(justfile.public_module_recipes(source_order))
+      .collect()
+  }
+
   fn dump(justfile: Justfile) -> Result<(), i32> {
     println!("{}", justfile);
     Ok(())
@@ -664,14 +790,14 @@ impl Config {
       }
     }
 
+    let recipes = Self::public_recipes(&justfile, self.unsorted);
+
     let mut line_widths: BTreeMap<&str, usize> = BTreeMap::new();
 
-    for (name, recipe) in &justfile.recipes {
-      if recipe.private {
-        continue;
-      }
+    for (name, recipe) in &recipes {
+      let name = name.as_str();
 
-      for name in iter::once(name).chain(recipe_aliases.get(name).unwrap_or(&Vec::new())) {
+      for name in iter::once(&name).chain(recipe_aliases.get(name).unwrap_or(&Vec::new())) {
         let mut line_width = UnicodeWidthStr::width(*name);
 
         for parameter in &recipe.parameters {
@@ -689,8 +815,8 @@ impl Config {
     let doc_color = self.color.stdout().doc();
     println!("Available recipes:");
 
-    for recipe in justfile.public_recipes(self.unsorted) {
-      let name = recipe.name();
+    for (name, recipe) in &recipes {
+      let name = name.as_str();
 
       for (i, name) in iter::once(&name)
         .chain(recipe_aliases.get(name).unwrap_or(&Vec::new()))
@@ -745,8 +871,40 @@ impl Config {
       warn!("Failed to set CTRL-C handler: {}", error)
     }
 
+    self.run_justfile(justfile, search, overrides, arguments)
+  }
+
+  /// Run `justfile`, and if it doesn't contain the requested recipes and has
+  /// `set fallback`, try again with the next justfile up the directory tree
+  fn run_justfile(
+    &self,
+    justfile: Justfile,
+    search: &Search,
+    overrides: &BTreeMap<String, String>,
+    arguments: &[String],
+  ) -> Result<(), i32> {
     let result = justfile.run(&self, search, overrides, arguments);
 
+    if let Err(RuntimeError::UnknownRecipes { .. }) = result {
+      if justfile.settings.fallback {
+        match search.search_parent_directory() {
+          Ok(search) => {
+            let loader = Loader::new();
+
+            let src = loader.load(&search.justfile).eprint(self.color)?;
+
+            let justfile = Compiler::compile(&loader, &search.justfile, src).eprint(self.color)?;
+
+            self.print_warnings(&justfile);
+
+            return self.run_justfile(justfile, &search, overrides, arguments);
+          },
+          Err(SearchError::NotFound) => {},
+          Err(error) => return Err(error).eprint(self.color),
+        }
+      }
+    }
+
     if !self.verbosity.quiet() {
       result.eprint(self.color)
     } else {
@@ -754,6 +912,16 @@ impl Config {
     }
   }
 
+  fn print_warnings(&self, justfile: &Justfile) {
+    for warning in &justfile.warnings {
+      if self.color.stderr().active() {
+        eprintln!("{:#}", warning);
+      } else {
+        eprintln!("{}", warning);
+      }
+    }
+  }
+
   fn show(name: &str, justfile: Justfile) -> Result<(), i32> {
     if let Some(alias) = justfile.get_alias(name) {
       let recipe = justfile.get_recipe(alias.target.name.lexeme()).unwrap();
@@ -776,11 +944,10 @@ impl Config {
     if justfile.count() == 0 {
       eprintln!("Justfile contains no recipes.");
     } else {
-      let summary = justfile
-        .public_recipes(self.unsorted)
-        .iter()
-        .map(|recipe| recipe.name())
-        .collect::<Vec<&str>>()
+      let summary = Self::public_recipes(&justfile, self.unsorted)
+        .into_iter()
+        .map(|(name, _recipe)| name)
+        .collect::<Vec<String>>()
         .join(" ");
       println!("{}", summary);
     }
@@ -797,6 +964,118 @@ impl Config {
     println!();
     Ok(())
   }
+
+  /// Run `arguments`, and run them again whenever a watched file changes,
+  /// until interrupted. The justfile is reloaded before each run. Changes
+  /// made while recipes are running, including by the recipes themselves,
+  /// are ignored.
+  fn watch(
+    &self,
+    search: &Search,
+    overrides: &BTreeMap<String, String>,
+    arguments: &[String],
+  ) -> Result<(), i32> {
+    const QUIET: Duration = Duration::from_millis(100);
+
+    if let Err(error) = InterruptHandler::install() {
+      warn!("Failed to set CTRL-C handler: {}", error)
+    }
+
+    let canonicalize = |path: &Path| path.canonicalize().unwrap_or_else(|_| path.to_owned());
+    let justfile_path = canonicalize(&search.justfile);
+    let working_directory = canonicalize(&search.working_directory);
+
+    let (sender, receiver) = mpsc::channel();
+
+    let mut watcher = match notify::recommended_watcher(sender) {
+      Ok(watcher) => watcher,
+      Err(error) => {
+        eprintln!("Failed to create file watcher: {}", error);
+        return Err(EXIT_FAILURE);
+      },
+    };
+
+    let mut paths = vec![(working_directory.as_path(), RecursiveMode::Recursive)];
+
+    // The justfile's directory is watched, rather than the justfile itself,
+    // so that changes are seen even if editors replace it
+    if let Some(directory) = justfile_path.parent() {
+      if !directory.starts_with(&working_directory) {
+        paths.push((directory, RecursiveMode::NonRecursive));
+      }
+    }
+
+    for (path, mode) in paths {
+      if let Err(error) = watcher.watch(path, mode) {
+        eprintln!("Failed to watch `{}`: {}", path.display(), error);
+        return Err(EXIT_FAILURE);
+      }
+    }
+
+    // Wait until no events have been received for `QUIET`
+    let settle = || while receiver.recv_timeout(QUIET).is_ok() {};
+
+    loop {
+      let loader = Loader::new();
+
+      let justfile = loader
+        .load(&search.justfile)
+        .eprint(self.color)
+        .and_then(|src| Compiler::compile(&loader, &search.justfile, src).eprint(self.color))
+        .ok();
+
+      let mut patterns = Vec::new();
+
+      if let Some(justfile) = &justfile {
+        self.print_warnings(justfile);
+
+        let mut recipes = arguments
+          .iter()
+          .filter_map(|argument| justfile.get_recipe(argument))
+          .collect::<Vec<&Recipe>>();
+
+        if recipes.is_empty() {
+          recipes.extend(justfile.first());
+        }
+
+        for recipe in recipes {
+          // Patterns are validated when the justfile is compiled
+          patterns.extend(
+            recipe
+              .watch_patterns()
+              .filter_map(|pattern| glob::Pattern::new(pattern).ok()),
+          );
+        }
+
+        let result = justfile.run(self, search, overrides, arguments);
+
+        if !self.verbosity.quiet() {
+          result.eprint(self.color).ok();
+        }
+      }
+
+      // Ignore changes made while recipes were running
+      settle();
+
+      let filter = WatchFilter::new(&justfile_path, &working_directory, patterns);
+
+      loop {
+        match receiver.recv() {
+          Ok(Ok(event)) =>
+            if !matches!(event.kind, EventKind::Access(_))
+              && event.paths.iter().any(|path| filter.matches(path))
+            {
+              break;
+            },
+          Ok(Err(error)) => warn!("File watcher error: {}", error),
+          Err(mpsc::RecvError) => return Err(EXIT_FAILURE),
+        }
+      }
+
+      // Wait for files to stop changing before running again
+      settle();
+    }
+  }
 }
 
 #[cfg(test)]
@@ -822,6 +1101,8 @@ FLAGS:
                                  not passed the chooser
                               defaults to the value of $JUST_CHOOSER, falling back to `fzf`
         --clear-shell-args    Clear shell arguments
+        --dotenv-load         Load environment file, even if disabled with `set dotenv-load := \
+                                 false`
         --dry-run             Print what just would do without doing it
         --dump                Print entire justfile
     -e, --edit                Edit justfile with editor given by $VISUAL or $EDITOR, falling back \
@@ -829,7 +1110,10 @@ FLAGS:
         --evaluate            Print evaluated variables
         --highlight           Highlight echoed recipe lines in bold
         --init                Initialize new justfile in project root
+        --keep-going          Keep running recipes after a recipe fails, skipping those that \
+                                 depend on it
     -l, --list                List available recipes and their arguments
+        --no-cache            Run `[cache]` recipes even if they are cached
         --no-dotenv           Don't load `.env` file
         --no-highlight        Don't highlight echoed recipe lines in bold
     -q, --quiet               Suppress all output
@@ -837,6 +1121,7 @@ FLAGS:
     -u, --unsorted            Return list and summary entries in source order
         --variables           List names of variables
     -v, --verbose             Use verbose output
+        --watch               Run recipes, and run them again whenever files change
 
 OPTIONS:
         --chooser <CHOOSER>                        Override binary invoked by `--choose`
@@ -847,12 +1132,21 @@ OPTIONS:
             Print shell completion script for <SHELL> [possible values: zsh, bash, fish, \
                                  powershell, elvish]
 
+        --dotenv-filename <DOTENV-FILENAME>        Search for environment file named \
+                                 <DOTENV-FILENAME> instead of `.env`
+        --dotenv-path <DOTENV-PATH>...
+            Load environment file at <DOTENV-PATH> instead of searching for `.env`
+
+    -j, --jobs <JOBS>                              Run up to <JOBS> recipes concurrently [default: \
+                                 1]
     -f, --justfile <JUSTFILE>                      Use <JUSTFILE> as justfile.
         --set <VARIABLE> <VALUE>                   Override <VARIABLE> with <VALUE>
         --shell <SHELL>                            Invoke <SHELL> to run recipes [default: sh]
         --shell-arg <SHELL-ARG>...                 Invoke shell with <SHELL-ARG> as an argument \
                                  [default: -cu]
     -s, --show <RECIPE>                            Show information about <RECIPE>
+        --timeout <TIMEOUT>                        Kill recipes that run longer than <TIMEOUT>, \
+                                 e.g. `30s` or `5m`
     -d, --working-directory <WORKING-DIRECTORY>
             Use <WORKING-DIRECTORY> as working directory. --justfile must also be set
 
@@ -874,13 +1168,20 @@ ARGS:
       name: $name:ident,
       args: [$($arg:expr),*],
       $(color: $color:expr,)?
+      $(dotenv_filename: $dotenv_filename:expr,)?
+      $(dotenv_load: $dotenv_load:expr,)?
+      $(dotenv_path: $dotenv_path:expr,)?
       $(dry_run: $dry_run:expr,)?
       $(highlight: $highlight:expr,)?
+      $(jobs: $jobs:expr,)?
+      $(keep_going: $keep_going:expr,)?
+      $(no_cache: $no_cache:expr,)?
       $(search_config: $search_config:expr,)?
       $(shell: $shell:expr,)?
       $(shell_args: $shell_args:expr,)?
       $(shell_present: $shell_present:expr,)?
       $(subcommand: $subcommand:expr,)?
+      $(timeout: $timeout:expr,)?
       $(unsorted: $unsorted:expr,)?
       $(verbosity: $verbosity:expr,)?
     } => {
@@ -893,13 +1194,20 @@ ARGS:
 
         let want = Config {
           $(color: $color,)?
+          $(dotenv_filename: $dotenv_filename.map(str::to_owned),)?
+          $(dotenv_load: $dotenv_load,)?
+          $(dotenv_path: $dotenv_path,)?
           $(dry_run: $dry_run,)?
           $(highlight: $highlight,)?
+          $(jobs: $jobs,)?
+          $(keep_going: $keep_going,)?
+          $(no_cache: $no_cache,)?
           $(search_config: $search_config,)?
           $(shell: $shell.to_string(),)?
           $(shell_args: $shell_args,)?
           $(shell_present: $shell_present,)?
           $(subcommand: $subcommand,)?
+          $(timeout: $timeout,)?
           $(unsorted: $unsorted,)?
           $(verbosity: $verbosity,)?
           ..testing::config(&[])
@@ -1065,6 +1373,81 @@ ARGS:
     highlight: false,
   }
 
+  test! {
+    name: jobs_default,
+    args: [],
+    jobs: 1,
+  }
+
+  test! {
+    name: jobs_long,
+    args: ["--jobs", "4"],
+    jobs: 4,
+  }
+
+  test! {
+    name: jobs_short,
+    args: ["-j", "2"],
+    jobs: 2,
+  }
+
+  test! {
+    name: no_cache_default,
+    args: [],
+    no_cache: false,
+  }
+
+  test! {
+    name: no_cache,
+    args: ["--no-cache"],
+    no_cache: true,
+  }
+
+  error! {
+    name: jobs_zero,
+    args: ["--jobs", "0"],
+  }
+
+  error! {
+    name: jobs_invalid,
+    args: ["--jobs", "many"],
+  }
+
+  test! {
+    name: timeout_default,
+    args: [],
+    timeout: None,
+  }
+
+  test! {
+    name: timeout_seconds,
+    args: ["--timeout", "30s"],
+    timeout: Some(Duration::from_secs(30)),
+  }
+
+  test! {
+    name: timeout_compound,
+    args: ["--timeout", "1m 30s"],
+    timeout: Some(Duration::from_secs(90)),
+  }
+
+  error! {
+    name: timeout_without_unit,
+    args: ["--timeout", "30"],
+  }
+
+  test! {
+    name: keep_going_default,
+    args: [],
+    keep_going: false,
+  }
+
+  test! {
+    name: keep_going,
+    args: ["--keep-going"],
+    keep_going: true,
+  }
+
   test! {
     name: unsorted_default,
     args: [],
@@ -1151,6 +1534,48 @@ ARGS:
     args: ["--set", "foo"],
   }
 
+  test! {
+    name: dotenv_default,
+    args: [],
+    dotenv_load: None,
+    dotenv_path: Vec::<PathBuf>::new(),
+  }
+
+  test! {
+    name: dotenv_filename,
+    args: ["--dotenv-filename", ".env.local"],
+    dotenv_filename: Some(".env.local"),
+  }
+
+  test! {
+    name: dotenv_load,
+    args: ["--dotenv-load"],
+    dotenv_load: Some(true),
+  }
+
+  test! {
+    name: dotenv_load_no_dotenv,
+    args: ["--dotenv-load", "--no-dotenv"],
+    dotenv_load: Some(false),
+  }
+
+  test! {
+    name: no_dotenv_dotenv_load,
+    args: ["--no-dotenv", "--dotenv-load"],
+    dotenv_load: Some(true),
+  }
+
+  test! {
+    name: dotenv_path,
+    args: ["--dotenv-path", "a", "--dotenv-path", "b"],
+    dotenv_path: vec![PathBuf::from("a"), PathBuf::from("b")],
+  }
+
+  error! {
+    name: dotenv_filename_and_path,
+    args: ["--dotenv-filename", "a", "--dotenv-path", "b"],
+  }
+
   test! {
     name: shell_default,
     args: [],
@@ -1245,6 +1670,11 @@ ARGS:
     args: ["--list", "--choose"],
   }
 
+  error! {
+    name: subcommand_conflict_watch,
+    args: ["--list", "--watch"],
+  }
+
   test! {
     name: subcommand_completions,
     args: ["--completions", "bash"],
@@ -1325,6 +1755,24 @@ ARGS:
     subcommand: Subcommand::Summary,
   }
 
+  test! {
+    name: subcommand_watch,
+    args: ["--watch"],
+    subcommand: Subcommand::Watch {
+      arguments: Vec::new(),
+      overrides: map!{},
+    },
+  }
+
+  test! {
+    name: subcommand_watch_arguments_and_overrides,
+    args: ["--watch", "x=y", "build", "test"],
+    subcommand: Subcommand::Watch {
+      arguments: vec![String::from("build"), String::from("test")],
+      overrides: map!{"x": "y"},
+    },
+  }
+
   test! {
     name: arguments,
     args: ["foo", "bar"],
@@ -2,22 +2,39 @@ use crate::common::*;
 
 #[derive(PartialEq, Debug)]
 pub(crate) struct Dependency<'src> {
-  pub(crate) recipe:    Rc<Recipe<'src>>,
+  pub(crate) modules:   Vec<Name<'src>>,
+  pub(crate) recipe:    Arc<Recipe<'src>>,
   pub(crate) arguments: Vec<Expression<'src>>,
 }
 
+impl<'src> Dependency<'src> {
+  /// Path of the module containing the dependency, relative to the module
+  /// containing the dependent recipe
+  pub(crate) fn module(&self) -> impl Iterator<Item = &'src str> + '_ {
+    self.modules.iter().map(Name::lexeme)
+  }
+}
+
 impl<'src> Display for Dependency<'src> {
   fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
-    if self.arguments.is_empty() {
-      write!(f, "{}", self.recipe.name())
-    } else {
-      write!(f, "({}", self.recipe.name())?;
+    if !self.arguments.is_empty() {
+      write!(f, "(")?;
+    }
 
+    for module in &self.modules {
+      write!(f, "{}::", module)?;
+    }
+
+    write!(f, "{}", self.recipe.name())?;
+
+    if !self.arguments.is_empty() {
       for argument in &self.arguments {
         write!(f, " {}", argument)?;
       }
 
-      write!(f, ")")
+      write!(f, ")")?;
     }
+
+    Ok(())
   }
 }
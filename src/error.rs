@@ -4,4 +4,8 @@ pub(crate) trait Error: Display {
   fn code(&self) -> i32 {
     EXIT_FAILURE
   }
+
+  fn print_message(&self) -> bool {
+    true
+  }
 }
@@ -9,10 +9,12 @@ impl<T, E: Error> ErrorResultExt<T> for Result<T, E> {
     match self {
       Ok(ok) => Ok(ok),
       Err(error) => {
-        if color.stderr().active() {
-          eprintln!("{}: {:#}", color.stderr().error().paint("error"), error);
-        } else {
-          eprintln!("error: {}", error);
+        if error.print_message() {
+          if color.stderr().active() {
+            eprintln!("{}: {:#}", color.stderr().error().paint("error"), error);
+          } else {
+            eprintln!("error: {}", error);
+          }
         }
 
         Err(error.code())
@@ -1,12 +1,15 @@
 use crate::common::*;
 
 pub(crate) struct Evaluator<'src: 'run, 'run> {
-  assignments: Option<&'run Table<'src, Assignment<'src>>>,
-  config:      &'run Config,
-  dotenv:      &'run BTreeMap<String, String>,
-  scope:       Scope<'src, 'run>,
-  settings:    &'run Settings<'run>,
-  search:      &'run Search,
+  assignments:       Option<&'run Table<'src, Assignment<'src>>>,
+  config:            &'run Config,
+  dotenv:            &'run BTreeMap<String, String>,
+  scope:             Scope<'src, 'run>,
+  settings:          &'run Settings<'run>,
+  search:            &'run Search,
+  /// Directory in which to run backticks, or `None` to run them in the
+  /// invocation directory
+  working_directory: Option<PathBuf>,
 }
 
 impl<'src, 'run> Evaluator<'src, 'run> {
@@ -21,6 +24,7 @@ impl<'src, 'run> Evaluator<'src, 'run> {
     let mut evaluator = Evaluator {
       scope: overrides,
       assignments: Some(assignments),
+      working_directory: Some(settings.working_directory(search)),
       config,
       dotenv,
       settings,
@@ -68,10 +72,16 @@ impl<'src, 'run> Evaluator<'src, 'run> {
       Expression::Call { thunk } => {
         use Thunk::*;
 
+        let working_directory = self
+          .working_directory
+          .clone()
+          .unwrap_or_else(|| self.config.invocation_directory.clone());
+
         let context = FunctionContext {
           dotenv:               self.dotenv,
           invocation_directory: &self.config.invocation_directory,
           search:               self.search,
+          working_directory:    &working_directory,
         };
 
         match thunk {
@@ -105,6 +115,42 @@ impl<'src, 'run> Evaluator<'src, 'run> {
             function: *name,
             message,
           }),
+          BinaryPlus {
+            name,
+            function,
+            args: ([a, b], rest),
+            ..
+          } => {
+            let a = self.evaluate_expression(a)?;
+            let b = self.evaluate_expression(b)?;
+
+            let mut rest_evaluated = Vec::new();
+            for arg in rest {
+              rest_evaluated.push(self.evaluate_expression(arg)?);
+            }
+
+            function(&context, &a, &b, &rest_evaluated).map_err(|message| {
+              RuntimeError::FunctionCall {
+                function: *name,
+                message,
+              }
+            })
+          },
+          Ternary {
+            name,
+            function,
+            args: [a, b, c],
+            ..
+          } => function(
+            &context,
+            &self.evaluate_expression(a)?,
+            &self.evaluate_expression(b)?,
+            &self.evaluate_expression(c)?,
+          )
+          .map_err(|message| RuntimeError::FunctionCall {
+            function: *name,
+            message,
+          }),
         }
       },
       Expression::StringLiteral { string_literal } => Ok(string_literal.cooked.to_string()),
@@ -116,34 +162,85 @@ impl<'src, 'run> Evaluator<'src, 'run> {
         },
       Expression::Concatination { lhs, rhs } =>
         Ok(self.evaluate_expression(lhs)? + &self.evaluate_expression(rhs)?),
-      Expression::Conditional {
-        lhs,
+      Expression::Join { lhs: None, rhs } => Ok(format!(
+        "/{}",
+        self.evaluate_expression(rhs)?.trim_start_matches('/')
+      )),
+      Expression::Join {
+        lhs: Some(lhs),
         rhs,
+      } => Ok(format!(
+        "{}/{}",
+        self.evaluate_expression(lhs)?.trim_end_matches('/'),
+        self.evaluate_expression(rhs)?.trim_start_matches('/'),
+      )),
+      Expression::Conditional {
+        condition,
         then,
         otherwise,
-        inverted,
-      } => {
-        let lhs = self.evaluate_expression(lhs)?;
-        let rhs = self.evaluate_expression(rhs)?;
-        let condition = if *inverted { lhs != rhs } else { lhs == rhs };
-        if condition {
+      } =>
+        if self.evaluate_condition(condition)? {
           self.evaluate_expression(then)
         } else {
           self.evaluate_expression(otherwise)
+        },
+      Expression::FormatString { kind, fragments } => {
+        let mut evaluated = String::new();
+        for fragment in fragments {
+          match fragment {
+            Fragment::Text { token } => {
+              evaluated += &kind
+                .cook(token.lexeme())
+                .map_err(|_| RuntimeError::Internal {
+                  message: format!("invalid escape sequence in format string `{}`", expression),
+                })?
+                .replace("{{{{", "{{");
+            },
+            Fragment::Interpolation { expression } => {
+              evaluated += &self.evaluate_expression(expression)?;
+            },
+          }
         }
+        Ok(evaluated)
       },
       Expression::Group { contents } => self.evaluate_expression(contents),
     }
   }
 
+  fn evaluate_condition(&mut self, condition: &Condition<'src>) -> RunResult<'src, bool> {
+    match condition {
+      Condition::Comparison { lhs, rhs, operator } => {
+        let lhs = self.evaluate_expression(lhs)?;
+        let rhs = self.evaluate_expression(rhs)?;
+        match operator {
+          ConditionalOperator::Equality => Ok(lhs == rhs),
+          ConditionalOperator::Inequality => Ok(lhs != rhs),
+          ConditionalOperator::RegexMatch => Ok(
+            Regex::new(&rhs)
+              .map_err(|regex_error| RuntimeError::RegexCompile { regex_error })?
+              .is_match(&lhs),
+          ),
+        }
+      },
+      Condition::And { lhs, rhs } =>
+        Ok(self.evaluate_condition(lhs)? && self.evaluate_condition(rhs)?),
+      Condition::Or { lhs, rhs } =>
+        Ok(self.evaluate_condition(lhs)? || self.evaluate_condition(rhs)?),
+      Condition::Not { operand } => Ok(!self.evaluate_condition(operand)?),
+    }
+  }
+
   fn run_backtick(&self, raw: &str, token: &Token<'src>) -> RunResult<'src, String> {
     let mut cmd = self.settings.shell_command(self.config);
 
     cmd.arg(raw);
 
-    cmd.current_dir(&self.search.working_directory);
+    if let Some(working_directory) = &self.working_directory {
+      cmd.current_dir(working_directory);
+    }
 
-    cmd.export(self.dotenv, &self.scope);
+    // export the bindings evaluated so far, as well as those in outer scopes
+    cmd.export(self.settings, self.dotenv, &Scope::child(&self.scope));
 
     cmd.stdin(process::Stdio::inherit());
 
@@ -186,28 +283,32 @@ impl<'src, 'run> Evaluator<'src, 'run> {
   pub(crate) fn evaluate_parameters(
     config: &'run Config,
     dotenv: &'run BTreeMap<String, String>,
-    parameters: &[Parameter<'src>],
+    recipe: &Recipe<'src>,
     arguments: &[&str],
     scope: &'run Scope<'src, 'run>,
     settings: &'run Settings,
     search: &'run Search,
-  ) -> RunResult<'src, Scope<'src, 'run>> {
-    let mut evaluator = Evaluator {
-      assignments: None,
-      scope: Scope::child(scope),
-      search,
-      settings,
-      dotenv,
+  ) -> RunResult<'src, (Scope<'src, 'run>, Vec<String>)> {
+    let mut evaluator = Self::recipe_evaluator(
       config,
-    };
+      dotenv,
+      scope,
+      settings,
+      search,
+      recipe.working_directory(settings, search),
+    );
 
     let mut scope = Scope::child(scope);
 
+    let mut positional = Vec::new();
+
     let mut rest = arguments;
-    for parameter in parameters {
+    for parameter in &recipe.parameters {
       let value = if rest.is_empty() {
         if let Some(ref default) = parameter.default {
-          evaluator.evaluate_expression(default)?
+          let value = evaluator.evaluate_expression(default)?;
+          positional.push(value.clone());
+          value
         } else if parameter.kind == ParameterKind::Star {
           String::new()
         } else {
@@ -216,18 +317,47 @@ impl<'src, 'run> Evaluator<'src, 'run> {
           });
         }
       } else if parameter.kind.is_variadic() {
+        for value in rest {
+          positional.push((*value).to_owned());
+        }
         let value = rest.to_vec().join(" ");
         rest = &[];
         value
       } else {
         let value = rest[0].to_owned();
+        positional.push(value.clone());
         rest = &rest[1..];
         value
       };
-      scope.bind(false, parameter.name, value);
+      scope.bind(parameter.export, parameter.name, value);
     }
 
-    Ok(scope)
+    Ok((scope, positional))
+  }
+
+  pub(crate) fn evaluate_locals(
+    config: &'run Config,
+    dotenv: &'run BTreeMap<String, String>,
+    recipe: &Recipe<'src>,
+    scope: &'run Scope<'src, 'run>,
+    settings: &'run Settings,
+    search: &'run Search,
+  ) -> RunResult<'src, Scope<'src, 'run>> {
+    let mut evaluator = Self::recipe_evaluator(
+      config,
+      dotenv,
+      scope,
+      settings,
+      search,
+      recipe.working_directory(settings, search),
+    );
+
+    for local in &recipe.locals {
+      let value = evaluator.evaluate_expression(&local.value)?;
+      evaluator.scope.bind(local.export, local.name, value);
+    }
+
+    Ok(evaluator.scope)
   }
 
   pub(crate) fn recipe_evaluator(
@@ -236,6 +366,7 @@ impl<'src, 'run> Evaluator<'src, 'run> {
     scope: &'run Scope<'src, 'run>,
     settings: &'run Settings,
     search: &'run Search,
+    working_directory: Option<PathBuf>,
   ) -> Evaluator<'src, 'run> {
     Evaluator {
       assignments: None,
@@ -244,6 +375,7 @@ impl<'src, 'run> Evaluator<'src, 'run> {
       settings,
       dotenv,
       config,
+      working_directory,
     }
   }
 }
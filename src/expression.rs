@@ -20,16 +20,25 @@ pub(crate) enum Expression<'src> {
     lhs: Box<Expression<'src>>,
     rhs: Box<Expression<'src>>,
   },
-  /// `if lhs == rhs { then } else { otherwise }`
+  /// `if condition { then } else { otherwise }`, with `else if` chains
+  /// represented as nested conditionals in `otherwise`
   Conditional {
-    lhs:       Box<Expression<'src>>,
-    rhs:       Box<Expression<'src>>,
+    condition: Condition<'src>,
     then:      Box<Expression<'src>>,
     otherwise: Box<Expression<'src>>,
-    inverted:  bool,
+  },
+  /// `f"fragments"` or `f'fragments'`
+  FormatString {
+    kind:      StringKind,
+    fragments: Vec<Fragment<'src>>,
   },
   /// `(contents)`
   Group { contents: Box<Expression<'src>> },
+  /// `lhs / rhs`, or `/ rhs` if `lhs` is `None`
+  Join {
+    lhs: Option<Box<Expression<'src>>>,
+    rhs: Box<Expression<'src>>,
+  },
   /// `"string_literal"` or `'string_literal'`
   StringLiteral { string_literal: StringLiteral<'src> },
   /// `variable`
@@ -47,21 +56,33 @@ impl<'src> Display for Expression<'src> {
     match self {
       Expression::Backtick { contents, .. } => write!(f, "`{}`", contents),
       Expression::Concatination { lhs, rhs } => write!(f, "{} + {}", lhs, rhs),
-      Expression::Conditional {
-        lhs,
+      Expression::Join { lhs: None, rhs } => write!(f, "/ {}", rhs),
+      Expression::Join {
+        lhs: Some(lhs),
         rhs,
+      } => write!(f, "{} / {}", lhs, rhs),
+      Expression::Conditional {
+        condition,
         then,
         otherwise,
-        inverted,
-      } => write!(
-        f,
-        "if {} {} {} {{ {} }} else {{ {} }} ",
-        lhs,
-        if *inverted { "!=" } else { "==" },
-        rhs,
-        then,
-        otherwise
-      ),
+      } => {
+        write!(f, "if {} {{ {} }} else ", condition, then)?;
+        if let Expression::Conditional { .. } = **otherwise {
+          write!(f, "{}", otherwise)
+        } else {
+          write!(f, "{{ {} }} ", otherwise)
+        }
+      },
+      Expression::FormatString { kind, fragments } => {
+        write!(f, "f{}", kind.delimiter())?;
+        for fragment in fragments {
+          match fragment {
+            Fragment::Text { token } => write!(f, "{}", token.lexeme())?,
+            Fragment::Interpolation { expression } => write!(f, "{{{{{}}}}}", expression)?,
+          }
+        }
+        write!(f, "{}", kind.delimiter())
+      },
       Expression::StringLiteral { string_literal } => write!(f, "{}", string_literal),
       Expression::Variable { name } => write!(f, "{}", name.lexeme()),
       Expression::Call { thunk } => write!(f, "{}", thunk),
@@ -2,10 +2,16 @@ use crate::common::*;
 
 use Function::*;
 
+use std::path::Component;
+
+use sha2::{Digest, Sha256};
+
 pub(crate) enum Function {
   Nullary(fn(&FunctionContext) -> Result<String, String>),
   Unary(fn(&FunctionContext, &str) -> Result<String, String>),
   Binary(fn(&FunctionContext, &str, &str) -> Result<String, String>),
+  BinaryPlus(fn(&FunctionContext, &str, &str, &[String]) -> Result<String, String>),
+  Ternary(fn(&FunctionContext, &str, &str, &str) -> Result<String, String>),
 }
 
 lazy_static! {
@@ -18,17 +24,37 @@ lazy_static! {
     ("invocation_directory", Nullary(invocation_directory)),
     ("env_var", Unary(env_var)),
     ("env_var_or_default", Binary(env_var_or_default)),
+    ("absolute_path", Unary(absolute_path)),
+    ("clean", Unary(clean)),
+    ("extension", Unary(extension)),
+    ("file_name", Unary(file_name)),
+    ("file_stem", Unary(file_stem)),
+    ("join", BinaryPlus(join)),
+    ("parent_directory", Unary(parent_directory)),
+    ("without_extension", Unary(without_extension)),
+    ("lowercase", Unary(lowercase)),
+    ("replace", Ternary(replace)),
+    ("replace_regex", Ternary(replace_regex)),
+    ("trim", Unary(trim)),
+    ("trim_end_match", Binary(trim_end_match)),
+    ("trim_start_match", Binary(trim_start_match)),
+    ("uppercase", Unary(uppercase)),
+    ("sha256", Unary(sha256)),
+    ("sha256_file", Unary(sha256_file)),
+    ("uuid", Nullary(uuid)),
   ]
   .into_iter()
   .collect();
 }
 
 impl Function {
-  pub(crate) fn argc(&self) -> usize {
+  pub(crate) fn argc(&self) -> RangeInclusive<usize> {
     match *self {
-      Nullary(_) => 0,
-      Unary(_) => 1,
-      Binary(_) => 2,
+      Nullary(_) => 0..=0,
+      Unary(_) => 1..=1,
+      Binary(_) => 2..=2,
+      BinaryPlus(_) => 2..=usize::MAX,
+      Ternary(_) => 3..=3,
     }
   }
 }
@@ -123,3 +149,155 @@ fn env_var_or_default(
     Ok(value) => Ok(value),
   }
 }
+
+fn absolute_path(context: &FunctionContext, path: &str) -> Result<String, String> {
+  let absolute = context.working_directory.join(path);
+
+  absolute.to_str().map(str::to_owned).ok_or_else(|| {
+    format!(
+      "Working directory is not valid unicode: {}",
+      context.working_directory.display()
+    )
+  })
+}
+
+fn clean(_context: &FunctionContext, path: &str) -> Result<String, String> {
+  let mut clean = Vec::new();
+
+  for component in Path::new(path).components() {
+    match component {
+      Component::CurDir => {},
+      Component::ParentDir => match clean.last() {
+        Some(Component::Normal(_)) => {
+          clean.pop();
+        },
+        Some(Component::RootDir) | Some(Component::Prefix(_)) => {},
+        Some(Component::CurDir) | Some(Component::ParentDir) | None => clean.push(component),
+      },
+      Component::Prefix(_) | Component::RootDir | Component::Normal(_) => clean.push(component),
+    }
+  }
+
+  if clean.is_empty() {
+    return Ok(".".to_owned());
+  }
+
+  Ok(utf8(&clean.into_iter().collect::<PathBuf>()))
+}
+
+fn extension(_context: &FunctionContext, path: &str) -> Result<String, String> {
+  Path::new(path)
+    .extension()
+    .map(utf8)
+    .ok_or_else(|| format!("Could not extract extension from `{}`", path))
+}
+
+fn file_name(_context: &FunctionContext, path: &str) -> Result<String, String> {
+  Path::new(path)
+    .file_name()
+    .map(utf8)
+    .ok_or_else(|| format!("Could not extract file name from `{}`", path))
+}
+
+fn file_stem(_context: &FunctionContext, path: &str) -> Result<String, String> {
+  Path::new(path)
+    .file_stem()
+    .map(utf8)
+    .ok_or_else(|| format!("Could not extract file stem from `{}`", path))
+}
+
+fn join(
+  _context: &FunctionContext,
+  base: &str,
+  with: &str,
+  and: &[String],
+) -> Result<String, String> {
+  let mut result = Path::new(base).join(with);
+  for arg in and {
+    result.push(arg);
+  }
+  Ok(utf8(&result))
+}
+
+fn parent_directory(_context: &FunctionContext, path: &str) -> Result<String, String> {
+  Path::new(path)
+    .parent()
+    .map(utf8)
+    .ok_or_else(|| format!("Could not extract parent directory from `{}`", path))
+}
+
+fn without_extension(_context: &FunctionContext, path: &str) -> Result<String, String> {
+  let parent = Path::new(path)
+    .parent()
+    .ok_or_else(|| format!("Could not extract parent from `{}`", path))?;
+
+  let file_stem = Path::new(path)
+    .file_stem()
+    .ok_or_else(|| format!("Could not extract file stem from `{}`", path))?;
+
+  Ok(utf8(&parent.join(file_stem)))
+}
+
+fn lowercase(_context: &FunctionContext, s: &str) -> Result<String, String> {
+  Ok(s.to_lowercase())
+}
+
+fn replace(_context: &FunctionContext, s: &str, from: &str, to: &str) -> Result<String, String> {
+  Ok(s.replace(from, to))
+}
+
+fn replace_regex(
+  _context: &FunctionContext,
+  s: &str,
+  regex: &str,
+  replacement: &str,
+) -> Result<String, String> {
+  Ok(
+    Regex::new(regex)
+      .map_err(|error| error.to_string())?
+      .replace_all(s, replacement)
+      .to_string(),
+  )
+}
+
+fn sha256(_context: &FunctionContext, s: &str) -> Result<String, String> {
+  let mut hasher = Sha256::new();
+  hasher.update(s);
+  Ok(format!("{:x}", hasher.finalize()))
+}
+
+fn sha256_file(context: &FunctionContext, path: &str) -> Result<String, String> {
+  let path = context.working_directory.join(path);
+  let mut file = fs::File::open(&path)
+    .map_err(|error| format!("Failed to open file at `{}`: {}", path.display(), error))?;
+  let mut hasher = Sha256::new();
+  io::copy(&mut file, &mut hasher)
+    .map_err(|error| format!("Failed to read file at `{}`: {}", path.display(), error))?;
+  Ok(format!("{:x}", hasher.finalize()))
+}
+
+fn trim(_context: &FunctionContext, s: &str) -> Result<String, String> {
+  Ok(s.trim().to_owned())
+}
+
+fn trim_end_match(_context: &FunctionContext, s: &str, pat: &str) -> Result<String, String> {
+  Ok(s.strip_suffix(pat).unwrap_or(s).to_owned())
+}
+
+fn trim_start_match(_context: &FunctionContext, s: &str, pat: &str) -> Result<String, String> {
+  Ok(s.strip_prefix(pat).unwrap_or(s).to_owned())
+}
+
+fn uppercase(_context: &FunctionContext, s: &str) -> Result<String, String> {
+  Ok(s.to_uppercase())
+}
+
+fn uuid(_context: &FunctionContext) -> Result<String, String> {
+  Ok(uuid::Uuid::new_v4().to_string())
+}
+
+/// Convert `path`, which was derived from a `&str` and is thus valid unicode,
+/// back into a `String`
+fn utf8(path: impl AsRef<OsStr>) -> String {
+  path.as_ref().to_string_lossy().into_owned()
+}
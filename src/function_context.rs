@@ -4,4 +4,6 @@ pub(crate) struct FunctionContext<'run> {
   pub(crate) dotenv:               &'run BTreeMap<String, String>,
   pub(crate) invocation_directory: &'run Path,
   pub(crate) search:               &'run Search,
+  /// Directory against which relative paths are resolved
+  pub(crate) working_directory:    &'run Path,
 }
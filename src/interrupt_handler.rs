@@ -61,7 +61,7 @@ impl InterruptHandler {
 
     self.blocks -= 1;
 
-    if self.interrupted {
+    if self.interrupted && self.blocks == 0 {
       Self::exit();
     }
   }
@@ -70,4 +70,96 @@ impl InterruptHandler {
     let _guard = InterruptGuard::new();
     function()
   }
+
+  /// Run `command` to completion with interrupts blocked, returning `None` if
+  /// it was killed because it was still running at `deadline`. If `cancel`
+  /// or `deadline` are given, the child is started in its own process group
+  /// and polled, and the group is killed if `cancel` is set, `deadline`
+  /// passes, or an interrupt is received. Since other children may be
+  /// running concurrently, this lets the last child to exit exit the process.
+  pub(crate) fn status(
+    command: &mut Command,
+    cancel: Option<&AtomicBool>,
+    deadline: Option<Instant>,
+  ) -> io::Result<Option<ExitStatus>> {
+    if cancel.is_none() && deadline.is_none() {
+      return Self::guard(|| command.status()).map(Some);
+    }
+
+    Self::guard(|| {
+      // Only one process group can be in the terminal's foreground, so only
+      // children run serially are put in it
+      let mut child = Platform::spawn_process_group(command, cancel.is_none())?;
+
+      let status = Self::poll(&mut child, cancel, deadline);
+
+      // Interrupts from the terminal are only sent to its foreground process
+      // group, so if the child was in it, treat its interruption as ours
+      if Platform::reclaim_terminal(&child)?
+        && matches!(
+          status,
+          Ok(Some(status)) if Platform::signal_from_exit_status(status) == Some(libc::SIGINT)
+        )
+      {
+        Self::instance().interrupted = true;
+      }
+
+      status
+    })
+  }
+
+  /// Wait for `child` to exit, killing its process group if `cancel` is set,
+  /// `deadline` passes, or an interrupt is received
+  fn poll(
+    child: &mut Child,
+    cancel: Option<&AtomicBool>,
+    deadline: Option<Instant>,
+  ) -> io::Result<Option<ExitStatus>> {
+    loop {
+      if let Some(exit_status) = child.try_wait()? {
+        return Ok(Some(exit_status));
+      }
+
+      if matches!(deadline, Some(deadline) if Instant::now() >= deadline) {
+        Platform::kill_process_group(child).ok();
+        child.wait()?;
+        return Ok(None);
+      }
+
+      if Self::cancelled(cancel) {
+        // The child may have exited since it was polled, in which case
+        // killing it fails, and waiting for it returns its exit status
+        Platform::kill_process_group(child).ok();
+        return child.wait().map(Some);
+      }
+
+      thread::sleep(Duration::from_millis(10));
+    }
+  }
+
+  /// Sleep for `duration`, waking early if `cancel` is set, `deadline`
+  /// passes, or an interrupt is received
+  pub(crate) fn sleep(duration: Duration, cancel: Option<&AtomicBool>, deadline: Option<Instant>) {
+    const INTERVAL: Duration = Duration::from_millis(10);
+
+    let end = match deadline {
+      Some(deadline) => deadline.min(Instant::now() + duration),
+      None => Instant::now() + duration,
+    };
+
+    loop {
+      let now = Instant::now();
+
+      if now >= end || Self::cancelled(cancel) {
+        return;
+      }
+
+      thread::sleep(INTERVAL.min(end - now));
+    }
+  }
+
+  /// Whether `cancel` is set or an interrupt has been received
+  pub(crate) fn cancelled(cancel: Option<&AtomicBool>) -> bool {
+    matches!(cancel, Some(cancel) if cancel.load(Ordering::SeqCst)) || Self::instance().interrupted
+  }
 }
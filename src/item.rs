@@ -5,6 +5,13 @@ use crate::common::*;
 pub(crate) enum Item<'src> {
   Alias(Alias<'src, Name<'src>>),
   Assignment(Assignment<'src>),
+  Import {
+    relative: StringLiteral<'src>,
+    token:    Token<'src>,
+  },
+  Mod {
+    name: Name<'src>,
+  },
   Recipe(UnresolvedRecipe<'src>),
   Set(Set<'src>),
 }
@@ -0,0 +1,53 @@
+use crate::common::*;
+
+/// A recipe invocation, with its parameters and locals evaluated, ready to run
+/// once the jobs that it depends on have finished
+pub(crate) struct Job<'src, 'run> {
+  pub(crate) context:      &'run RecipeContext<'src, 'run>,
+  /// Indices of the jobs that must finish before this job may run
+  pub(crate) dependencies: BTreeSet<usize>,
+  /// The recipe's qualified name followed by its arguments
+  pub(crate) invocation:   Vec<String>,
+  pub(crate) positional:   Vec<String>,
+  pub(crate) recipe:       &'run Recipe<'src>,
+  /// Scope containing the recipe's parameters and locals
+  pub(crate) scope:        &'run Scope<'src, 'run>,
+}
+
+impl<'src, 'run> Job<'src, 'run> {
+  /// Run the job's recipe, unless it is up to date and none of the jobs that
+  /// it depends on ran, returning whether it ran
+  pub(crate) fn run(
+    &self,
+    dotenv: &BTreeMap<String, String>,
+    dependencies_ran: bool,
+    cancel: Option<&AtomicBool>,
+  ) -> RunResult<'src, bool> {
+    if !dependencies_ran && self.recipe.is_up_to_date(self.context) {
+      let config = self.context.config;
+
+      if config.verbosity.loquacious() {
+        let color = config.color.stderr().banner();
+        eprintln!(
+          "{}===> Recipe `{}` is up to date{}",
+          color.prefix(),
+          self.recipe.name(),
+          color.suffix()
+        );
+      }
+
+      return Ok(false);
+    }
+
+    // Commands export the parents of the scope they run in, so run the recipe
+    // in a child scope, in order to export parameters and locals
+    self.recipe.run(
+      self.context,
+      dotenv,
+      Scope::child(self.scope),
+      self.context.search,
+      &self.positional,
+      cancel,
+    )
+  }
+}
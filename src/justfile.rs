@@ -2,11 +2,15 @@ use crate::common::*;
 
 #[derive(Debug, PartialEq)]
 pub(crate) struct Justfile<'src> {
-  pub(crate) recipes:     Table<'src, Rc<Recipe<'src>>>,
+  pub(crate) recipes:     Table<'src, Arc<Recipe<'src>>>,
   pub(crate) assignments: Table<'src, Assignment<'src>>,
   pub(crate) aliases:     Table<'src, Alias<'src>>,
+  pub(crate) modules:     BTreeMap<&'src str, Justfile<'src>>,
   pub(crate) settings:    Settings<'src>,
   pub(crate) warnings:    Vec<Warning<'src>>,
+  /// Location of a submodule's source file and working directory, `None` for
+  /// the root justfile, whose location is determined at runtime
+  pub(crate) search:      Option<Search>,
 }
 
 impl<'src> Justfile<'src> {
@@ -14,7 +18,9 @@ impl<'src> Justfile<'src> {
     let mut first: Option<&Recipe<Dependency>> = None;
     for recipe in self.recipes.values() {
       if let Some(first_recipe) = first {
-        if recipe.line_number() < first_recipe.line_number() {
+        if (recipe.name.path, recipe.line_number())
+          < (first_recipe.name.path, first_recipe.line_number())
+        {
           first = Some(recipe)
         }
       } else {
@@ -78,23 +84,66 @@ impl<'src> Justfile<'src> {
 
     let arguments = argvec.as_slice();
 
-    let unknown_overrides = overrides
-      .keys()
-      .filter(|name| !self.assignments.contains_key(name.as_str()))
-      .map(String::as_str)
-      .collect::<Vec<&str>>();
+    // Recipes are looked up before anything is evaluated, so that nothing is
+    // evaluated if this justfile falls back to a parent justfile
+    let mut missing = vec![];
+    let mut grouped = vec![];
+    let mut rest = arguments;
+
+    while let Some((argument, mut tail)) = rest.split_first() {
+      if let Some((module, recipe)) = self.lookup(argument) {
+        if recipe.parameters.is_empty() {
+          grouped.push((module, recipe, &[][..]));
+        } else {
+          let argument_range = recipe.argument_range();
+          let argument_count = cmp::min(tail.len(), recipe.max_arguments());
+          if !argument_range.range_contains(&argument_count) {
+            return Err(RuntimeError::ArgumentCountMismatch {
+              recipe:     recipe.name(),
+              parameters: recipe.parameters.iter().collect(),
+              found:      tail.len(),
+              min:        recipe.min_arguments(),
+              max:        recipe.max_arguments(),
+            });
+          }
+          grouped.push((module, recipe, &tail[0..argument_count]));
+          tail = &tail[argument_count..];
+        }
+      } else {
+        missing.push(*argument);
+      }
+      rest = tail;
+    }
+
+    // Overrides are checked against the parent justfile instead if this one
+    // falls back to it
+    if missing.is_empty() || !self.settings.fallback {
+      let unknown_overrides = overrides
+        .keys()
+        .filter(|name| !self.assignments.contains_key(name.as_str()))
+        .map(String::as_str)
+        .collect::<Vec<&str>>();
+
+      if !unknown_overrides.is_empty() {
+        return Err(RuntimeError::UnknownOverrides {
+          overrides: unknown_overrides,
+        });
+      }
+    }
 
-    if !unknown_overrides.is_empty() {
-      return Err(RuntimeError::UnknownOverrides {
-        overrides: unknown_overrides,
+    if !missing.is_empty() {
+      let suggestion = if missing.len() == 1 {
+        self.suggest(missing.first().unwrap())
+      } else {
+        None
+      };
+      return Err(RuntimeError::UnknownRecipes {
+        recipes: missing,
+        suggestion,
       });
     }
 
-    let dotenv = if config.load_dotenv {
-      load_dotenv(&search.working_directory)?
-    } else {
-      BTreeMap::new()
-    };
+    let dotenv = load_dotenv(config, &self.settings, &search.working_directory)?;
 
     let scope = {
       let mut scope = Scope::new();
@@ -142,67 +191,43 @@ impl<'src> Justfile<'src> {
       return Ok(());
     }
 
-    let mut missing = vec![];
-    let mut grouped = vec![];
-    let mut rest = arguments;
-
-    while let Some((argument, mut tail)) = rest.split_first() {
-      if let Some(recipe) = self.get_recipe(argument) {
-        if recipe.parameters.is_empty() {
-          grouped.push((recipe, &[][..]));
-        } else {
-          let argument_range = recipe.argument_range();
-          let argument_count = cmp::min(tail.len(), recipe.max_arguments());
-          if !argument_range.range_contains(&argument_count) {
-            return Err(RuntimeError::ArgumentCountMismatch {
-              recipe:     recipe.name(),
-              parameters: recipe.parameters.iter().collect(),
-              found:      tail.len(),
-              min:        recipe.min_arguments(),
-              max:        recipe.max_arguments(),
-            });
-          }
-          grouped.push((recipe, &tail[0..argument_count]));
-          tail = &tail[argument_count..];
-        }
-      } else {
-        missing.push(*argument);
-      }
-      rest = tail;
-    }
-
-    if !missing.is_empty() {
-      let suggestion = if missing.len() == 1 {
-        self.suggest(missing.first().unwrap())
-      } else {
-        None
-      };
-      return Err(RuntimeError::UnknownRecipes {
-        recipes: missing,
-        suggestion,
-      });
-    }
+    let context_arena = Arena::new();
+    let scope_arena = Arena::new();
 
-    let context = RecipeContext {
-      settings: &self.settings,
+    let mut plan = Plan::new(
+      self,
       config,
-      scope,
-      search,
-    };
+      &dotenv,
+      RecipeContext {
+        module: Vec::new(),
+        settings: &self.settings,
+        config,
+        scope,
+        search,
+      },
+      &context_arena,
+      &scope_arena,
+    );
 
-    let mut ran = BTreeSet::new();
-    for (recipe, arguments) in grouped {
-      self.run_recipe(&context, recipe, arguments, &dotenv, &search, &mut ran)?
+    for (module, recipe, arguments) in grouped {
+      plan.add(&module, recipe, arguments)?;
     }
 
-    Ok(())
+    plan.run()
   }
 
   pub(crate) fn get_alias(&self, name: &str) -> Option<&Alias> {
     self.aliases.get(name)
   }
 
-  pub(crate) fn get_recipe(&self, name: &str) -> Option<&Recipe<'src>> {
+  /// Get the recipe or alias target named by `path`, which may be qualified
+  /// with a module path, e.g. `foo::bar::baz`
+  pub(crate) fn get_recipe(&self, path: &str) -> Option<&Recipe<'src>> {
+    self.lookup(path).map(|(_module, recipe)| recipe)
+  }
+
+  /// Get the recipe or alias target named `name` defined in this module
+  pub(crate) fn get_local_recipe(&self, name: &str) -> Option<&Recipe<'src>> {
     if let Some(recipe) = self.recipes.get(name) {
       Some(recipe)
     } else if let Some(alias) = self.aliases.get(name) {
@@ -212,55 +237,51 @@ impl<'src> Justfile<'src> {
     }
   }
 
-  fn run_recipe<'run>(
+  /// Get the submodule at `path`, which is relative to this module
+  pub(crate) fn get_module<'a>(
     &self,
-    context: &'run RecipeContext<'src, 'run>,
-    recipe: &Recipe<'src>,
-    arguments: &[&'run str],
-    dotenv: &BTreeMap<String, String>,
-    search: &'run Search,
-    ran: &mut BTreeSet<Vec<String>>,
-  ) -> RunResult<'src, ()> {
-    let scope = Evaluator::evaluate_parameters(
-      context.config,
-      dotenv,
-      &recipe.parameters,
-      arguments,
-      &context.scope,
-      context.settings,
-      search,
-    )?;
-
-    let mut evaluator =
-      Evaluator::recipe_evaluator(context.config, dotenv, &scope, context.settings, search);
-
-    for Dependency { recipe, arguments } in &recipe.dependencies {
-      let mut invocation = vec![recipe.name().to_owned()];
-
-      for argument in arguments {
-        invocation.push(evaluator.evaluate_expression(argument)?);
-      }
+    path: impl IntoIterator<Item = &'a str>,
+  ) -> Option<&Justfile<'src>> {
+    let mut module = self;
 
-      if !ran.contains(&invocation) {
-        let arguments = invocation
-          .iter()
-          .skip(1)
-          .map(String::as_ref)
-          .collect::<Vec<&str>>();
-        self.run_recipe(context, recipe, &arguments, dotenv, search, ran)?;
-      }
+    for name in path {
+      module = module.modules.get(name)?;
     }
 
-    recipe.run(context, dotenv, scope, search)?;
+    Some(module)
+  }
+
+  /// Get the module path and recipe named by `path`
+  fn lookup<'path>(&self, path: &'path str) -> Option<(Vec<&'path str>, &Recipe<'src>)> {
+    let mut module = path.split("::").collect::<Vec<&str>>();
 
-    let mut invocation = Vec::new();
-    invocation.push(recipe.name().to_owned());
-    for argument in arguments.iter().cloned() {
-      invocation.push(argument.to_owned());
+    let name = module.pop()?;
+
+    let recipe = self
+      .get_module(module.iter().cloned())?
+      .get_local_recipe(name)?;
+
+    Some((module, recipe))
+  }
+
+  /// Public recipes of all submodules, along with their qualified names
+  pub(crate) fn public_module_recipes(
+    &self,
+    source_order: bool,
+  ) -> Vec<(String, &Recipe<Dependency>)> {
+    let mut recipes = Vec::new();
+
+    for (name, module) in &self.modules {
+      for recipe in module.public_recipes(source_order) {
+        recipes.push((format!("{}::{}", name, recipe.name()), recipe));
+      }
+
+      for (path, recipe) in module.public_module_recipes(source_order) {
+        recipes.push((format!("{}::{}", name, path), recipe));
+      }
     }
 
-    ran.insert(invocation);
-    Ok(())
+    recipes
   }
 
   pub(crate) fn public_recipes(&self, source_order: bool) -> Vec<&Recipe<Dependency>> {
@@ -272,7 +293,7 @@ impl<'src> Justfile<'src> {
       .collect::<Vec<&Recipe<Dependency>>>();
 
     if source_order {
-      recipes.sort_by_key(|recipe| recipe.name.offset);
+      recipes.sort_by_key(|recipe| (recipe.name.path, recipe.name.offset));
     }
 
     recipes
@@ -281,7 +302,15 @@ impl<'src> Justfile<'src> {
 
 impl<'src> Display for Justfile<'src> {
   fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
-    let mut items = self.recipes.len() + self.assignments.len() + self.aliases.len();
+    let mut items =
+      self.modules.len() + self.recipes.len() + self.assignments.len() + self.aliases.len();
+    for name in self.modules.keys() {
+      write!(f, "{} {}", Keyword::Mod.lexeme(), name)?;
+      items -= 1;
+      if items != 0 {
+        write!(f, "\n\n")?;
+      }
+    }
     for (name, assignment) in &self.assignments {
       if assignment.export {
         write!(f, "export ")?;
@@ -374,6 +403,7 @@ mod tests {
       recipe,
       line_number,
       code,
+      print_message: true,
     },
     check: {
       assert_eq!(recipe, "a");
@@ -393,6 +423,7 @@ mod tests {
       recipe,
       line_number,
       code,
+      print_message: true,
     },
     check: {
       assert_eq!(recipe, "fail");
@@ -412,6 +443,7 @@ mod tests {
       recipe,
       line_number,
       code,
+      print_message: true,
     },
     check: {
       assert_eq!(recipe, "a");
@@ -569,6 +601,7 @@ mod tests {
       code: _,
       line_number,
       recipe,
+      print_message: true,
     },
     check: {
       assert_eq!(recipe, "wut");
@@ -617,6 +650,23 @@ foo a="b\t":
     r#"foo a="b\t":"#,
   }
 
+  test! {
+    parse_attributes,
+    "
+# doc
+[no-exit-message, private]
+[no-cd]
+@foo:
+  echo foo
+",
+    "# doc
+[no-cd]
+[no-exit-message]
+[private]
+@foo:
+    echo foo",
+  }
+
   test! {
   parse_multiple,
     r#"
@@ -4,11 +4,22 @@ use crate::common::*;
 #[strum(serialize_all = "kebab_case")]
 pub(crate) enum Keyword {
   Alias,
+  DotenvFilename,
+  DotenvLoad,
+  DotenvPath,
   Else,
   Export,
+  Fallback,
+  False,
   If,
+  Import,
+  Let,
+  Mod,
+  PositionalArguments,
   Set,
   Shell,
+  True,
+  WorkingDirectory,
 }
 
 impl Keyword {
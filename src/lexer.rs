@@ -12,6 +12,8 @@ use TokenKind::*;
 /// slight against regular expressions, the lexer was just idiosyncratically
 /// bad.
 pub(crate) struct Lexer<'src> {
+  /// Source path, relative to the root justfile's directory
+  path:                &'src Path,
   /// Source text
   src:                 &'src str,
   /// Char iterator
@@ -28,22 +30,37 @@ pub(crate) struct Lexer<'src> {
   recipe_body_pending: bool,
   /// Inside recipe body
   recipe_body:         bool,
+  /// Next recipe body line may be a recipe-local assignment
+  recipe_locals:       bool,
+  /// Inside recipe-local assignment
+  recipe_local:        bool,
   /// Indentation stack
   indentation:         Vec<&'src str>,
   /// Current interpolation start token
   interpolation_start: Option<Token<'src>>,
   /// Current open delimiters
   open_delimiters:     Vec<(Delimiter, usize)>,
+  /// Open format strings, each with its start token and the interpolation
+  /// it was opened in, if any
+  format_strings:      Vec<(Token<'src>, StringKind, Option<Token<'src>>)>,
 }
 
 impl<'src> Lexer<'src> {
-  /// Lex `text`
+  /// Lex `text`, the contents of the root justfile
   pub(crate) fn lex(src: &str) -> CompilationResult<Vec<Token>> {
-    Lexer::new(src).tokenize()
+    Lexer::new(Path::new(""), src).tokenize()
+  }
+
+  /// Lex `text`, the contents of the imported justfile at `path`
+  pub(crate) fn lex_import(
+    path: &'src Path,
+    src: &'src str,
+  ) -> CompilationResult<'src, Vec<Token<'src>>> {
+    Lexer::new(path, src).tokenize()
   }
 
   /// Create a new Lexer to lex `text`
-  fn new(src: &'src str) -> Lexer<'src> {
+  fn new(path: &'src Path, src: &'src str) -> Lexer<'src> {
     let mut chars = src.chars();
     let next = chars.next();
 
@@ -60,10 +77,14 @@ impl<'src> Lexer<'src> {
       token_end: start,
       recipe_body_pending: false,
       recipe_body: false,
+      recipe_locals: false,
+      recipe_local: false,
       interpolation_start: None,
       open_delimiters: Vec::new(),
+      format_strings: Vec::new(),
       chars,
       next,
+      path,
       src,
     }
   }
@@ -167,6 +188,7 @@ impl<'src> Lexer<'src> {
       offset: self.token_start.offset,
       column: self.token_start.column,
       line: self.token_start.line,
+      path: self.path,
       src: self.src,
       length: self.token_end.offset - self.token_start.offset,
       kind,
@@ -180,6 +202,7 @@ impl<'src> Lexer<'src> {
   fn internal_error(&self, message: impl Into<String>) -> CompilationError<'src> {
     // Use `self.token_end` as the location of the error
     let token = Token {
+      path:   self.path,
       src:    self.src,
       offset: self.token_end.offset,
       line:   self.token_end.line,
@@ -211,6 +234,7 @@ impl<'src> Lexer<'src> {
 
     let token = Token {
       kind: Unspecified,
+      path: self.path,
       src: self.src,
       offset: self.token_start.offset,
       line: self.token_start.line,
@@ -248,6 +272,22 @@ impl<'src> Lexer<'src> {
     true
   }
 
+  /// True if `text` starts with a recipe-local assignment, e.g. `let foo :=`
+  fn is_recipe_local(text: &str) -> bool {
+    let blank = |c| c == ' ' || c == '\t';
+
+    let rest = match text.strip_prefix(Keyword::Let.lexeme()) {
+      Some(rest) if rest.starts_with(blank) => rest.trim_start_matches(blank),
+      _ => return false,
+    };
+
+    let end = rest
+      .find(|c| !Self::is_identifier_continue(c))
+      .unwrap_or_else(|| rest.len());
+
+    Self::is_identifier(&rest[..end]) && rest[end..].trim_start_matches(blank).starts_with(":=")
+  }
+
   /// True if `c` can be the first character of an identifier
   fn is_identifier_start(c: char) -> bool {
     matches!(c, 'a'..='z' | 'A'..='Z' | '_')
@@ -273,7 +313,9 @@ impl<'src> Lexer<'src> {
         Some(first) => {
           if let Some(interpolation_start) = self.interpolation_start {
             self.lex_interpolation(interpolation_start, first)?
-          } else if self.recipe_body {
+          } else if let Some(&(_, kind, _)) = self.format_strings.last() {
+            self.lex_format_string(kind)?
+          } else if self.recipe_body && !self.recipe_local {
             self.lex_body()?
           } else {
             self.lex_normal(first)?
@@ -287,6 +329,10 @@ impl<'src> Lexer<'src> {
       return Err(Self::unterminated_interpolation_error(interpolation_start));
     }
 
+    if let Some((start, ..)) = self.format_strings.last() {
+      return Err(start.error(UnterminatedString));
+    }
+
     while self.indented() {
       self.lex_dedent();
     }
@@ -442,6 +488,7 @@ impl<'src> Lexer<'src> {
           self.token(Indent);
           if self.recipe_body_pending {
             self.recipe_body = true;
+            self.recipe_locals = true;
           }
         }
 
@@ -453,27 +500,32 @@ impl<'src> Lexer<'src> {
   /// Lex token beginning with `start` outside of a recipe body
   fn lex_normal(&mut self, start: char) -> CompilationResult<'src, ()> {
     match start {
-      '!' => self.lex_bang(),
+      '!' => self.lex_choice('=', BangEquals, Bang),
+      '$' => self.lex_single(Dollar),
+      '&' => self.lex_digraph('&', '&', AmpersandAmpersand),
       '*' => self.lex_single(Asterisk),
       '@' => self.lex_single(At),
       '[' => self.lex_delimiter(BracketL),
       ']' => self.lex_delimiter(BracketR),
-      '=' => self.lex_choice('=', EqualsEquals, Equals),
+      '=' => self.lex_equals(),
       ',' => self.lex_single(Comma),
       ':' => self.lex_colon(),
       '(' => self.lex_delimiter(ParenL),
       ')' => self.lex_delimiter(ParenR),
       '{' => self.lex_delimiter(BraceL),
       '}' => self.lex_delimiter(BraceR),
+      '|' => self.lex_digraph('|', '|', BarBar),
       '+' => self.lex_single(Plus),
+      '/' => self.lex_single(Slash),
       '#' => self.lex_comment(),
       '`' => self.lex_backtick(),
       ' ' => self.lex_whitespace(),
-      '"' => self.lex_cooked_string(),
-      '\'' => self.lex_raw_string(),
+      '"' | '\'' => self.lex_string(),
       '\n' => self.lex_eol(),
       '\r' => self.lex_eol(),
       '\t' => self.lex_whitespace(),
+      'f' if self.rest_starts_with("f\"") || self.rest_starts_with("f'") =>
+        self.lex_format_string_start(),
       _ if Self::is_identifier_start(start) => self.lex_identifier(),
       _ => {
         self.advance()?;
@@ -505,6 +557,20 @@ impl<'src> Lexer<'src> {
 
   /// Lex token while in recipe body
   fn lex_body(&mut self) -> CompilationResult<'src, ()> {
+    if self.recipe_locals {
+      if Self::is_recipe_local(self.rest()) {
+        // lex the rest of the line as per normal
+        self.recipe_local = true;
+        return Ok(());
+      }
+
+      // recipe-local assignments may be separated by blank lines, but must
+      // precede all other lines
+      if !(self.rest_starts_with("\n") || self.rest_starts_with("\r\n")) {
+        self.recipe_locals = false;
+      }
+    }
+
     enum Terminator {
       Newline,
       NewlineCarriageReturn,
@@ -551,12 +617,78 @@ impl<'src> Lexer<'src> {
     }
   }
 
+  /// Lex the opening `f"` or `f'` of a format string
+  fn lex_format_string_start(&mut self) -> CompilationResult<'src, ()> {
+    self.presume('f')?;
+
+    let kind = if self.next_is('"') {
+      StringKind::Cooked
+    } else {
+      StringKind::Raw
+    };
+
+    self.advance()?;
+    self.token(FormatStringStart);
+
+    let start = self.tokens[self.tokens.len() - 1];
+    self
+      .format_strings
+      .push((start, kind, self.interpolation_start.take()));
+
+    Ok(())
+  }
+
+  /// Lex token while inside a format string of kind `kind`. Text is emitted
+  /// as `Text` tokens, and interpolations are lexed as they are in recipe
+  /// bodies. `{{{{` is an escaped `{{`, and is included in the text.
+  fn lex_format_string(&mut self, kind: StringKind) -> CompilationResult<'src, ()> {
+    let mut escape = false;
+
+    loop {
+      if self.at_eol_or_eof() {
+        let (start, ..) = self.format_strings[self.format_strings.len() - 1];
+        return Err(start.error(UnterminatedString));
+      } else if self.rest_starts_with("{{{{") && !escape {
+        for _ in 0..4 {
+          self.advance()?;
+        }
+        continue;
+      } else if kind.processes_escape_sequences() && self.next_is('\\') && !escape {
+        escape = true;
+      } else if (self.rest_starts_with("{{") || self.rest_starts_with(kind.delimiter())) && !escape
+      {
+        break;
+      } else {
+        escape = false;
+      }
+
+      self.advance()?;
+    }
+
+    if self.current_token_length() > 0 {
+      self.token(Text);
+    }
+
+    if self.rest_starts_with("{{") {
+      self.lex_double(InterpolationStart)?;
+      self.interpolation_start = Some(self.tokens[self.tokens.len() - 1]);
+    } else {
+      self.lex_single(FormatStringEnd)?;
+      if let Some((_, _, interpolation_start)) = self.format_strings.pop() {
+        self.interpolation_start = interpolation_start;
+      }
+    }
+
+    Ok(())
+  }
+
   fn lex_dedent(&mut self) {
     assert_eq!(self.current_token_length(), 0);
     self.token(Dedent);
     self.indentation.pop();
     self.recipe_body_pending = false;
     self.recipe_body = false;
+    self.recipe_locals = false;
   }
 
   /// Lex a single-character token
@@ -641,29 +773,54 @@ impl<'src> Lexer<'src> {
     !self.open_delimiters.is_empty()
   }
 
-  /// Lex a token starting with '!'
-  fn lex_bang(&mut self) -> CompilationResult<'src, ()> {
-    self.presume('!')?;
+  /// Lex a two-character token of kind `token`, consisting of `left`
+  /// followed by `right`
+  fn lex_digraph(
+    &mut self,
+    left: char,
+    right: char,
+    token: TokenKind,
+  ) -> CompilationResult<'src, ()> {
+    self.presume(left)?;
 
-    if self.accepted('=')? {
-      self.token(BangEquals);
+    if self.accepted(right)? {
+      self.token(token);
       Ok(())
     } else {
       // Emit an unspecified token to consume the current character,
       self.token(Unspecified);
-      // …and advance past another character,
-      self.advance()?;
+      // …and advance past another character, if there is one,
+      if self.next.is_some() {
+        self.advance()?;
+      }
       // …so that the error we produce highlights the unexpected character.
-      Err(self.error(UnexpectedCharacter { expected: '=' }))
+      Err(self.error(UnexpectedCharacter { expected: right }))
     }
   }
 
+  /// Lex a token starting with '='
+  fn lex_equals(&mut self) -> CompilationResult<'src, ()> {
+    self.presume('=')?;
+
+    if self.accepted('=')? {
+      self.token(EqualsEquals);
+    } else if self.accepted('~')? {
+      self.token(EqualsTilde);
+    } else {
+      self.token(Equals);
+    }
+
+    Ok(())
+  }
+
   /// Lex a token starting with ':'
   fn lex_colon(&mut self) -> CompilationResult<'src, ()> {
     self.presume(':')?;
 
     if self.accepted('=')? {
       self.token(ColonEquals);
+    } else if self.accepted(':')? {
+      self.token(ColonColon);
     } else {
       self.token(Colon);
       self.recipe_body_pending = true;
@@ -683,8 +840,12 @@ impl<'src> Lexer<'src> {
     }
 
     // Emit an eol if there are no open delimiters, otherwise emit a whitespace
-    // token.
-    if self.open_delimiters() {
+    // token. Recipe-local assignments always end at the end of the line.
+    if self.recipe_local {
+      self.recipe_local = false;
+      self.open_delimiters.clear();
+      self.token(Eol);
+    } else if self.open_delimiters() {
       self.token(Whitespace);
     } else {
       self.token(Eol);
@@ -753,48 +914,44 @@ impl<'src> Lexer<'src> {
     Ok(())
   }
 
-  /// Lex raw string: '[^']*'
-  fn lex_raw_string(&mut self) -> CompilationResult<'src, ()> {
-    self.presume('\'')?;
-
-    loop {
-      match self.next {
-        Some('\'') => break,
-        None => return Err(self.error(UnterminatedString)),
-        Some(_) => {},
-      }
+  /// Lex a string literal: `'raw'`, `"cooked"`, `'''indented raw'''`, or
+  /// `"""indented cooked"""`. Cooked strings may contain escape sequences,
+  /// which are processed by the parser. Non-indented cooked strings may not
+  /// contain newlines.
+  fn lex_string(&mut self) -> CompilationResult<'src, ()> {
+    let kind = if let Some(kind) = StringKind::from_token_start(self.rest()) {
+      kind
+    } else {
+      return Err(self.internal_error("Lexer::lex_string: invalid string start"));
+    };
 
-      self.advance()?;
+    for c in kind.delimiter().chars() {
+      self.presume(c)?;
     }
 
-    self.presume('\'')?;
-
-    self.token(StringRaw);
-
-    Ok(())
-  }
-
-  /// Lex cooked string: "[^"\n\r]*" (also processes escape sequences)
-  fn lex_cooked_string(&mut self) -> CompilationResult<'src, ()> {
-    self.presume('"')?;
-
     let mut escape = false;
 
     loop {
-      match self.next {
-        Some('\r') | Some('\n') | None => return Err(self.error(UnterminatedString)),
-        Some('"') if !escape => break,
-        Some('\\') if !escape => escape = true,
-        _ => escape = false,
+      if self.next.is_none()
+        || (kind.forbids_newlines() && (self.next_is('\r') || self.next_is('\n')))
+      {
+        return Err(self.error(UnterminatedString));
+      } else if kind.processes_escape_sequences() && self.next_is('\\') && !escape {
+        escape = true;
+      } else if self.rest_starts_with(kind.delimiter()) && !escape {
+        break;
+      } else {
+        escape = false;
       }
 
       self.advance()?;
     }
 
-    // advance over closing "
-    self.advance()?;
+    for c in kind.delimiter().chars() {
+      self.presume(c)?;
+    }
 
-    self.token(StringCooked);
+    self.token(kind.token_kind());
 
     Ok(())
   }
@@ -889,32 +1046,39 @@ mod tests {
     match kind {
       // Fixed lexemes
       Asterisk => "*",
+      AmpersandAmpersand => "&&",
       At => "@",
+      Bang => "!",
       BangEquals => "!=",
+      BarBar => "||",
       BraceL => "{",
       BraceR => "}",
       BracketL => "[",
       BracketR => "]",
       Colon => ":",
+      ColonColon => "::",
       ColonEquals => ":=",
       Comma => ",",
+      Dollar => "$",
       Eol => "\n",
       Equals => "=",
       EqualsEquals => "==",
+      EqualsTilde => "=~",
       Indent => "  ",
       InterpolationEnd => "}}",
       InterpolationStart => "{{",
       ParenL => "(",
       ParenR => ")",
       Plus => "+",
+      Slash => "/",
       Whitespace => " ",
 
       // Empty lexemes
       Dedent | Eof => "",
 
       // Variable lexemes
-      Text | StringCooked | StringRaw | Identifier | Comment | Backtick | Unspecified =>
-        panic!("Token {:?} has no default lexeme", kind),
+      Text | StringCooked | StringRaw | Identifier | Comment | Backtick | FormatStringEnd
+      | FormatStringStart | Unspecified => panic!("Token {:?} has no default lexeme", kind),
     }
   }
 
@@ -949,6 +1113,7 @@ mod tests {
         let want = CompilationError {
           token: Token {
             kind: have.token.kind,
+            path: Path::new(""),
             src,
             offset,
             line,
@@ -992,6 +1157,204 @@ mod tests {
     tokens: (StringCooked:"\"hello\""),
   }
 
+  test! {
+    name:   indented_raw_string,
+    text:   "'''\n  hello\n  ''' a",
+    tokens: (StringRaw:"'''\n  hello\n  '''", Whitespace, Identifier:"a"),
+  }
+
+  test! {
+    name:   indented_cooked_string,
+    text:   "\"\"\"\n  hello \\\"\"\" \n  \"\"\" a",
+    tokens: (StringCooked:"\"\"\"\n  hello \\\"\"\" \n  \"\"\"", Whitespace, Identifier:"a"),
+  }
+
+  test! {
+    name:   indented_string_contains_delimiter,
+    text:   "''' ' '' ''' \"\"\" \" \"\" \"\"\"",
+    tokens: (
+      StringRaw:"''' ' '' '''",
+      Whitespace,
+      StringCooked:"\"\"\" \" \"\" \"\"\"",
+    ),
+  }
+
+  test! {
+    name:   dollar,
+    text:   "a $b:",
+    tokens: (Identifier:"a", Whitespace, Dollar, Identifier:"b", Colon),
+  }
+
+  test! {
+    name:   recipe_local,
+    text:   "a:\n let b := 'c'\n let\td:='e'\n echo\n let f := g",
+    tokens: (
+      Identifier:"a",
+      Colon,
+      Eol,
+      Indent:" ",
+      Identifier:"let",
+      Whitespace,
+      Identifier:"b",
+      Whitespace,
+      ColonEquals,
+      Whitespace,
+      StringRaw:"'c'",
+      Eol,
+      Whitespace:" ",
+      Identifier:"let",
+      Whitespace:"\t",
+      Identifier:"d",
+      ColonEquals,
+      StringRaw:"'e'",
+      Eol,
+      Whitespace:" ",
+      Text:"echo",
+      Eol,
+      Whitespace:" ",
+      Text:"let f := g",
+      Dedent,
+    ),
+  }
+
+  test! {
+    name:   recipe_local_lookalikes,
+    text:   "a:\n let b = c\n letb := c\n let b",
+    tokens: (
+      Identifier:"a",
+      Colon,
+      Eol,
+      Indent:" ",
+      Text:"let b = c",
+      Eol,
+      Whitespace:" ",
+      Text:"letb := c",
+      Eol,
+      Whitespace:" ",
+      Text:"let b",
+      Dedent,
+    ),
+  }
+
+  test! {
+    name:   format_string,
+    text:   "f\"{{a}}-{{b}}\" f",
+    tokens: (
+      FormatStringStart:"f\"",
+      InterpolationStart,
+      Identifier:"a",
+      InterpolationEnd,
+      Text:"-",
+      InterpolationStart,
+      Identifier:"b",
+      InterpolationEnd,
+      FormatStringEnd:"\"",
+      Whitespace,
+      Identifier:"f",
+    ),
+  }
+
+  test! {
+    name:   format_string_raw,
+    text:   "f'\\n{{ a }}'",
+    tokens: (
+      FormatStringStart:"f'",
+      Text:"\\n",
+      InterpolationStart,
+      Whitespace,
+      Identifier:"a",
+      Whitespace,
+      InterpolationEnd,
+      FormatStringEnd:"'",
+    ),
+  }
+
+  test! {
+    name:   format_string_escaped_delimiter,
+    text:   "f\"\\\"{{a}}\"",
+    tokens: (
+      FormatStringStart:"f\"",
+      Text:"\\\"",
+      InterpolationStart,
+      Identifier:"a",
+      InterpolationEnd,
+      FormatStringEnd:"\"",
+    ),
+  }
+
+  test! {
+    name:   format_string_escaped_interpolation,
+    text:   "f'{{{{a}}-{{b}}'",
+    tokens: (
+      FormatStringStart:"f'",
+      Text:"{{{{a}}-",
+      InterpolationStart,
+      Identifier:"b",
+      InterpolationEnd,
+      FormatStringEnd:"'",
+    ),
+  }
+
+  test! {
+    name:   format_string_nested,
+    text:   "f'{{f\"{{a}}\"}}'",
+    tokens: (
+      FormatStringStart:"f'",
+      InterpolationStart,
+      FormatStringStart:"f\"",
+      InterpolationStart,
+      Identifier:"a",
+      InterpolationEnd,
+      FormatStringEnd:"\"",
+      InterpolationEnd,
+      FormatStringEnd:"'",
+    ),
+  }
+
+  test! {
+    name:   format_string_in_recipe_body,
+    text:   "a:\n echo {{f'{{b}}'}}",
+    tokens: (
+      Identifier:"a",
+      Colon,
+      Eol,
+      Indent:" ",
+      Text:"echo ",
+      InterpolationStart,
+      FormatStringStart:"f'",
+      InterpolationStart,
+      Identifier:"b",
+      InterpolationEnd,
+      FormatStringEnd:"'",
+      InterpolationEnd,
+      Dedent,
+    ),
+  }
+
+  test! {
+    name:   indented_string_followed_by_tokens,
+    text:   "
+      x := '''
+        foo
+      '''
+      y := x
+    ",
+    tokens: (
+      Identifier:"x",
+      Whitespace,
+      ColonEquals,
+      Whitespace,
+      StringRaw:"'''\n  foo\n'''",
+      Eol,
+      Identifier:"y",
+      Whitespace,
+      ColonEquals,
+      Whitespace,
+      Identifier:"x",
+      Eol,
+    ),
+  }
+
   test! {
     name:   equals,
     text:   "=",
@@ -1004,12 +1367,42 @@ mod tests {
     tokens: (EqualsEquals),
   }
 
+  test! {
+    name:   bang,
+    text:   "!",
+    tokens: (Bang),
+  }
+
+  test! {
+    name:   ampersand_ampersand,
+    text:   "&&",
+    tokens: (AmpersandAmpersand),
+  }
+
+  test! {
+    name:   bar_bar,
+    text:   "||",
+    tokens: (BarBar),
+  }
+
+  test! {
+    name:   equals_tilde,
+    text:   "=~",
+    tokens: (EqualsTilde),
+  }
+
   test! {
     name:   bang_equals,
     text:   "!=",
     tokens: (BangEquals),
   }
 
+  test! {
+    name:   slash,
+    text:   "/",
+    tokens: (Slash),
+  }
+
   test! {
     name:   brace_l,
     text:   "{",
@@ -1779,6 +2172,19 @@ mod tests {
     ),
   }
 
+  test! {
+    name: colon_colon,
+    text: "a: b::c",
+    tokens: (
+      Identifier:"a",
+      Colon,
+      Whitespace,
+      Identifier:"b",
+      ColonColon,
+      Identifier:"c",
+    ),
+  }
+
   test! {
     name: tokenize_comment,
     text: "a:=#",
@@ -2011,8 +2417,8 @@ mod tests {
   }
 
   error! {
-    name:   unknown_start_of_token_ampersand,
-    input:  " \r\n&",
+    name:   unknown_start_of_token_caret,
+    input:  " \r\n^",
     offset: 3,
     line:   1,
     column: 0,
@@ -2060,6 +2466,26 @@ mod tests {
     kind:   UnterminatedString,
   }
 
+  error! {
+    name:   unterminated_format_string,
+    input:  "a = f'{{b}}",
+    offset: 4,
+    line:   0,
+    column: 4,
+    width:  2,
+    kind:   UnterminatedString,
+  }
+
+  error! {
+    name:   unterminated_format_string_newline,
+    input:  "a = f\"b\nc\"",
+    offset: 4,
+    line:   0,
+    column: 4,
+    width:  2,
+    kind:   UnterminatedString,
+  }
+
   error! {
     name:   mixed_leading_whitespace_recipe,
     input:  "a:\n\t echo hello",
@@ -2111,13 +2537,53 @@ mod tests {
   }
 
   error! {
-    name:   unexpected_character_after_bang,
-    input:  "!{",
+    name:   unterminated_indented_raw_string,
+    input:  "a = '''\nfoo''",
+    offset: 4,
+    line:   0,
+    column: 4,
+    width:  1,
+    kind:   UnterminatedString,
+  }
+
+  error! {
+    name:   unterminated_indented_cooked_string,
+    input:  "a = \"\"\"\nfoo\\\"\"\"",
+    offset: 4,
+    line:   0,
+    column: 4,
+    width:  1,
+    kind:   UnterminatedString,
+  }
+
+  error! {
+    name:   unexpected_character_after_ampersand,
+    input:  "&{",
+    offset: 1,
+    line:   0,
+    column: 1,
+    width:  1,
+    kind:   UnexpectedCharacter { expected: '&' },
+  }
+
+  error! {
+    name:   ampersand_at_end_of_text,
+    input:  "&",
+    offset: 1,
+    line:   0,
+    column: 1,
+    width:  0,
+    kind:   UnexpectedCharacter { expected: '&' },
+  }
+
+  error! {
+    name:   unexpected_character_after_bar,
+    input:  "|{",
     offset: 1,
     line:   0,
     column: 1,
     width:  1,
-    kind:   UnexpectedCharacter { expected: '=' },
+    kind:   UnexpectedCharacter { expected: '|' },
   }
 
   error! {
@@ -2137,7 +2603,7 @@ mod tests {
   #[test]
   fn presume_error() {
     assert_matches!(
-      Lexer::new("!").presume('-').unwrap_err(),
+      Lexer::new(Path::new(""), "!").presume('-').unwrap_err(),
       CompilationError {
         token: Token {
           offset: 0,
@@ -2146,6 +2612,7 @@ mod tests {
           length: 0,
           src:    "!",
           kind:   Unspecified,
+          ..
         },
         kind:  Internal {
           message,
@@ -2154,7 +2621,10 @@ mod tests {
     );
 
     assert_eq!(
-      Lexer::new("!").presume('-').unwrap_err().to_string(),
+      Lexer::new(Path::new(""), "!")
+        .presume('-')
+        .unwrap_err()
+        .to_string(),
       testing::unindent(
         "
         Internal error, this may indicate a bug in just: Lexer presumed character `-`
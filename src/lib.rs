@@ -56,13 +56,17 @@ mod alias;
 mod analyzer;
 mod assignment;
 mod assignment_resolver;
+mod attribute;
 mod binding;
+mod cache;
 mod color;
 mod command_ext;
 mod common;
 mod compilation_error;
 mod compilation_error_kind;
 mod compiler;
+mod condition;
+mod conditional_operator;
 mod config;
 mod config_error;
 mod count;
@@ -81,6 +85,7 @@ mod function_context;
 mod interrupt_guard;
 mod interrupt_handler;
 mod item;
+mod job;
 mod justfile;
 mod keyed;
 mod keyword;
@@ -89,14 +94,17 @@ mod line;
 mod list;
 mod load_dotenv;
 mod load_error;
+mod loader;
 mod module;
 mod name;
 mod ordinal;
+mod outcome;
 mod output;
 mod output_error;
 mod parameter;
 mod parameter_kind;
 mod parser;
+mod plan;
 mod platform;
 mod platform_interface;
 mod position;
@@ -116,6 +124,7 @@ mod setting;
 mod settings;
 mod shebang;
 mod show_whitespace;
+mod string_kind;
 mod string_literal;
 mod subcommand;
 mod suggestion;
@@ -123,12 +132,14 @@ mod table;
 mod thunk;
 mod token;
 mod token_kind;
+mod unindent;
 mod unresolved_dependency;
 mod unresolved_recipe;
 mod use_color;
 mod variables;
 mod verbosity;
 mod warning;
+mod watch_filter;
 
 pub use crate::run::run;
 
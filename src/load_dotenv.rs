@@ -1,26 +1,85 @@
 use crate::common::*;
 
+const DEFAULT_DOTENV_FILENAME: &str = ".env";
+
+/// Load environment variables from the dotenv files selected by `config` and
+/// `settings`, with command line flags taking precedence over settings.
+///
+/// If dotenv paths are given, each file is loaded in order, and values in
+/// later files override those in earlier files. Otherwise, the working
+/// directory and its ancestors are searched for a file named
+/// `dotenv-filename`, defaulting to `.env`, and the first one found is
+/// loaded. Variables already present in the environment are never
+/// overridden.
 pub(crate) fn load_dotenv(
+  config: &Config,
+  settings: &Settings,
   working_directory: &Path,
 ) -> RunResult<'static, BTreeMap<String, String>> {
+  if !config.dotenv_load.or(settings.dotenv_load).unwrap_or(true) {
+    return Ok(BTreeMap::new());
+  }
+
+  let paths = if !config.dotenv_path.is_empty() {
+    config.dotenv_path.clone()
+  } else if config.dotenv_filename.is_none() && !settings.dotenv_path.is_empty() {
+    settings
+      .dotenv_path
+      .iter()
+      .map(|path| working_directory.join(path))
+      .collect()
+  } else {
+    let filename = config
+      .dotenv_filename
+      .as_deref()
+      .or(settings.dotenv_filename.as_deref())
+      .unwrap_or(DEFAULT_DOTENV_FILENAME);
+
+    working_directory
+      .ancestors()
+      .map(|directory| directory.join(filename))
+      .find(|path| path.is_file())
+      .into_iter()
+      .collect()
+  };
+
+  let mut dotenv = BTreeMap::new();
+
+  for path in paths {
+    if config.verbosity.loquacious() {
+      let color = config.color.stderr().banner();
+      eprintln!(
+        "{}===> Loading environment file `{}`...{}",
+        color.prefix(),
+        path
+          .strip_prefix(working_directory)
+          .unwrap_or(&path)
+          .display(),
+        color.suffix()
+      );
+    }
+
+    load_dotenv_file(&path, &mut dotenv)?;
+  }
+
+  Ok(dotenv)
+}
+
+fn load_dotenv_file(path: &Path, dotenv: &mut BTreeMap<String, String>) -> RunResult<'static, ()> {
   // `dotenv::from_path_iter` should eventually be un-deprecated, see:
   // https://github.com/dotenv-rs/dotenv/issues/13
   #![allow(deprecated)]
-  for directory in working_directory.ancestors() {
-    let path = directory.join(".env");
-
-    if path.is_file() {
-      let iter = dotenv::from_path_iter(&path)?;
-      let mut dotenv = BTreeMap::new();
-      for result in iter {
-        let (key, value) = result?;
-        if env::var_os(&key).is_none() {
-          dotenv.insert(key, value);
-        }
-      }
-      return Ok(dotenv);
+  let error = |dotenv_error| RuntimeError::Dotenv {
+    path: path.to_owned(),
+    dotenv_error,
+  };
+
+  for result in dotenv::from_path_iter(path).map_err(error)? {
+    let (key, value) = result.map_err(error)?;
+    if env::var_os(&key).is_none() {
+      dotenv.insert(key, value);
     }
   }
 
-  Ok(BTreeMap::new())
+  Ok(())
 }
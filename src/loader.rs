@@ -0,0 +1,33 @@
+use crate::common::*;
+
+/// Owner of the source text and paths of every justfile loaded during a single
+/// invocation. Tokens, names, and everything built from them borrow from the
+/// loader, so it must outlive the `Justfile` that the `Compiler` produces.
+pub(crate) struct Loader {
+  srcs:  Arena<String>,
+  paths: Arena<PathBuf>,
+}
+
+impl Loader {
+  pub(crate) fn new() -> Loader {
+    Loader {
+      srcs:  Arena::new(),
+      paths: Arena::new(),
+    }
+  }
+
+  /// Read the justfile at `path`
+  pub(crate) fn load<'src, 'path>(
+    &'src self,
+    path: &'path Path,
+  ) -> Result<&'src str, LoadError<'path>> {
+    let src = fs::read_to_string(path).map_err(|io_error| LoadError { path, io_error })?;
+
+    Ok(self.srcs.alloc(src))
+  }
+
+  /// Store `path`, so that it may be referenced by tokens
+  pub(crate) fn path(&self, path: PathBuf) -> &Path {
+    self.paths.alloc(path)
+  }
+}
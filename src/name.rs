@@ -8,6 +8,7 @@ pub(crate) struct Name<'src> {
   pub(crate) length: usize,
   pub(crate) line:   usize,
   pub(crate) column: usize,
+  pub(crate) path:   &'src Path,
   pub(crate) src:    &'src str,
 }
 
@@ -25,6 +26,7 @@ impl<'src> Name<'src> {
       length: self.length,
       line:   self.line,
       column: self.column,
+      path:   self.path,
       src:    self.src,
     }
   }
@@ -36,6 +38,7 @@ impl<'src> Name<'src> {
       length: token.length,
       line:   token.line,
       column: token.column,
+      path:   token.path,
       src:    token.src,
     }
   }
@@ -20,6 +20,9 @@ impl<'src> Node<'src> for Item<'src> {
     match self {
       Item::Alias(alias) => alias.tree(),
       Item::Assignment(assignment) => assignment.tree(),
+      Item::Import { relative, .. } =>
+        Tree::atom(Keyword::Import.lexeme()).push(Tree::string(&relative.cooked)),
+      Item::Mod { name } => Tree::atom(Keyword::Mod.lexeme()).push(name.lexeme()),
       Item::Recipe(recipe) => recipe.tree(),
       Item::Set(set) => set.tree(),
     }
@@ -48,25 +51,41 @@ impl<'src> Node<'src> for Assignment<'src> {
   }
 }
 
+impl<'src> Node<'src> for Condition<'src> {
+  fn tree(&self) -> Tree<'src> {
+    match self {
+      Condition::Comparison { lhs, rhs, operator } => Tree::atom(operator.to_string())
+        .push(lhs.tree())
+        .push(rhs.tree()),
+      Condition::And { lhs, rhs } => Tree::atom("&&").push(lhs.tree()).push(rhs.tree()),
+      Condition::Or { lhs, rhs } => Tree::atom("||").push(lhs.tree()).push(rhs.tree()),
+      Condition::Not { operand } => Tree::atom("!").push(operand.tree()),
+    }
+  }
+}
+
 impl<'src> Node<'src> for Expression<'src> {
   fn tree(&self) -> Tree<'src> {
     match self {
       Expression::Concatination { lhs, rhs } => Tree::atom("+").push(lhs.tree()).push(rhs.tree()),
-      Expression::Conditional {
-        lhs,
+      Expression::Join { lhs: None, rhs } => Tree::atom("/").push(rhs.tree()),
+      Expression::Join {
+        lhs: Some(lhs),
         rhs,
+      } => Tree::atom("/").push(lhs.tree()).push(rhs.tree()),
+      Expression::Conditional {
+        condition,
         then,
         otherwise,
-        inverted,
       } => {
         let mut tree = Tree::atom(Keyword::If.lexeme());
-        tree.push_mut(lhs.tree());
-        if *inverted {
-          tree.push_mut("!=")
+        if let Condition::Comparison { lhs, rhs, operator } = condition {
+          tree.push_mut(lhs.tree());
+          tree.push_mut(operator.to_string());
+          tree.push_mut(rhs.tree());
         } else {
-          tree.push_mut("==")
+          tree.push_mut(condition.tree());
         }
-        tree.push_mut(rhs.tree());
         tree.push_mut(then.tree());
         tree.push_mut(otherwise.tree());
         tree
@@ -88,6 +107,28 @@ impl<'src> Node<'src> for Expression<'src> {
             tree.push_mut(a.tree());
             tree.push_mut(b.tree());
           },
+          BinaryPlus {
+            name,
+            args: ([a, b], rest),
+            ..
+          } => {
+            tree.push_mut(name.lexeme());
+            tree.push_mut(a.tree());
+            tree.push_mut(b.tree());
+            for arg in rest {
+              tree.push_mut(arg.tree());
+            }
+          },
+          Ternary {
+            name,
+            args: [a, b, c],
+            ..
+          } => {
+            tree.push_mut(name.lexeme());
+            tree.push_mut(a.tree());
+            tree.push_mut(b.tree());
+            tree.push_mut(c.tree());
+          },
         }
 
         tree
@@ -98,6 +139,13 @@ impl<'src> Node<'src> for Expression<'src> {
       } => Tree::string(cooked),
       Expression::Backtick { contents, .. } => Tree::atom("backtick").push(Tree::string(contents)),
       Expression::Group { contents } => Tree::List(vec![contents.tree()]),
+      Expression::FormatString { fragments, .. } => {
+        let mut tree = Tree::atom("f");
+        for fragment in fragments {
+          tree.push_mut(fragment.tree());
+        }
+        tree
+      },
     }
   }
 }
@@ -115,6 +163,23 @@ impl<'src> Node<'src> for UnresolvedRecipe<'src> {
       t.push_mut(Tree::string(doc));
     }
 
+    if !self.attributes.is_empty() {
+      t.push_mut(
+        Tree::atom("attributes").extend(self.attributes.iter().map(|attribute| {
+          let arguments = attribute.arguments();
+          if arguments.is_empty() {
+            Tree::atom(attribute.name())
+          } else {
+            Tree::atom(attribute.name()).extend(
+              arguments
+                .iter()
+                .map(|argument| Tree::string(&argument.cooked)),
+            )
+          }
+        })),
+      );
+    }
+
     t.push_mut(self.name.lexeme());
 
     if !self.parameters.is_empty() {
@@ -131,20 +196,44 @@ impl<'src> Node<'src> for UnresolvedRecipe<'src> {
       t.push_mut(params);
     }
 
-    if !self.dependencies.is_empty() {
-      let mut dependencies = Tree::atom("deps");
+    let (priors, subsequents) = self.dependencies.split_at(self.priors);
 
-      for dependency in &self.dependencies {
-        let mut d = Tree::atom(dependency.recipe.lexeme());
+    for (atom, dependencies) in &[("deps", priors), ("sups", subsequents)] {
+      if dependencies.is_empty() {
+        continue;
+      }
+
+      let mut tree = Tree::atom(*atom);
+
+      for dependency in *dependencies {
+        let mut d = if dependency.modules.is_empty() {
+          Tree::atom(dependency.recipe.lexeme())
+        } else {
+          let mut path = dependency
+            .modules
+            .iter()
+            .map(Name::lexeme)
+            .collect::<Vec<&str>>();
+          path.push(dependency.recipe.lexeme());
+          Tree::atom(path.join("::"))
+        };
 
         for argument in &dependency.arguments {
           d.push_mut(argument.tree());
         }
 
-        dependencies.push_mut(d);
+        tree.push_mut(d);
       }
 
-      t.push_mut(dependencies);
+      t.push_mut(tree);
+    }
+
+    for local in &self.locals {
+      t.push_mut(
+        Tree::atom(Keyword::Let.lexeme())
+          .push(local.name.lexeme())
+          .push(local.value.tree()),
+      );
     }
 
     if !self.body.is_empty() {
@@ -158,6 +247,12 @@ impl<'src> Node<'src> for UnresolvedRecipe<'src> {
 impl<'src> Node<'src> for Parameter<'src> {
   fn tree(&self) -> Tree<'src> {
     let mut children = Vec::new();
+
+    if self.export {
+      children.push(Tree::atom("#"));
+      children.push(Tree::atom(Keyword::Export.lexeme()));
+    }
+
     children.push(Tree::atom(self.name.lexeme()));
 
     if let Some(default) = &self.default {
@@ -191,6 +286,16 @@ impl<'src> Node<'src> for Set<'src> {
 
     use Setting::*;
     match &self.value {
+      DotenvFilename(path) | WorkingDirectory(path) => {
+        set.push_mut(Tree::string(&path.cooked));
+      },
+      DotenvPath(paths) =>
+        for path in paths {
+          set.push_mut(Tree::string(&path.cooked));
+        },
+      DotenvLoad(value) | Export(value) | Fallback(value) | PositionalArguments(value) => {
+        set.push_mut(value.to_string());
+      },
       Shell(setting::Shell { command, arguments }) => {
         set.push_mut(Tree::string(&command.cooked));
         for argument in arguments {
@@ -207,6 +312,7 @@ impl<'src> Node<'src> for Warning<'src> {
   fn tree(&self) -> Tree<'src> {
     match self {
       Warning::DeprecatedEquals { .. } => Tree::atom("warning").push("deprecated_equals"),
+      Warning::UnusedRecipeLocal { .. } => Tree::atom("warning").push("unused_recipe_local"),
     }
   }
 }
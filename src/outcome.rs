@@ -0,0 +1,30 @@
+use crate::common::*;
+
+/// The outcome of a job, reported in the summary printed by `--keep-going`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Outcome {
+  Failed,
+  Passed,
+  /// The job didn't run, because a job it depends on failed or was skipped
+  Skipped,
+}
+
+impl Outcome {
+  pub(crate) fn color(self, color: Color) -> Color {
+    match self {
+      Self::Failed => color.error(),
+      Self::Passed => color,
+      Self::Skipped => color.warning(),
+    }
+  }
+}
+
+impl Display for Outcome {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    match self {
+      Self::Failed => write!(f, "failed"),
+      Self::Passed => write!(f, "passed"),
+      Self::Skipped => write!(f, "skipped"),
+    }
+  }
+}
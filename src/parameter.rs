@@ -9,6 +9,8 @@ pub(crate) struct Parameter<'src> {
   pub(crate) kind:    ParameterKind,
   /// An optional default expression
   pub(crate) default: Option<Expression<'src>>,
+  /// Export parameter as an environment variable
+  pub(crate) export:  bool,
 }
 
 impl<'src> Display for Parameter<'src> {
@@ -17,6 +19,9 @@ impl<'src> Display for Parameter<'src> {
     if let Some(prefix) = self.kind.prefix() {
       write!(f, "{}", color.annotation().paint(prefix))?;
     }
+    if self.export {
+      write!(f, "$")?;
+    }
     write!(f, "{}", color.parameter().paint(self.name.lexeme()))?;
     if let Some(ref default) = self.default {
       write!(f, "={}", color.string().paint(&default.to_string()))?;
@@ -0,0 +1,470 @@
+use crate::common::*;
+
+use std::mem;
+
+/// The jobs to run for a single invocation of `just`, a graph of jobs whose
+/// indices are the order in which they would run serially. Unless `--jobs` is
+/// greater than one, each job runs as soon as it is added, so that parameters,
+/// locals, and dependency arguments are evaluated just before the recipe runs.
+/// Otherwise, they are all evaluated up front, and the jobs run concurrently
+/// once the plan is complete.
+pub(crate) struct Plan<'src, 'run> {
+  config:        &'run Config,
+  context_arena: &'run Arena<RecipeContext<'src, 'run>>,
+  contexts:      BTreeMap<Vec<&'run str>, &'run RecipeContext<'src, 'run>>,
+  dotenv:        &'run BTreeMap<String, String>,
+  /// Errors from jobs run serially, which are only collected with
+  /// `--keep-going`
+  errors:        Vec<RuntimeError<'src>>,
+  /// For each job, the jobs that must finish before it is considered finished,
+  /// namely itself and, transitively, its subsequents
+  finished:      Vec<BTreeSet<usize>>,
+  jobs:          Vec<Job<'src, 'run>>,
+  justfile:      &'run Justfile<'src>,
+  /// Outcomes of jobs run serially
+  outcomes:      Vec<Outcome>,
+  /// Index of the most recent job for each invocation, used to run each
+  /// dependency only once
+  ran:           BTreeMap<Vec<String>, usize>,
+  /// Whether each job run serially ran, rather than being skipped as up to
+  /// date
+  ran_jobs:      Vec<bool>,
+  scope_arena:   &'run Arena<Scope<'src, 'run>>,
+}
+
+impl<'src, 'run> Plan<'src, 'run> {
+  pub(crate) fn new(
+    justfile: &'run Justfile<'src>,
+    config: &'run Config,
+    dotenv: &'run BTreeMap<String, String>,
+    context: RecipeContext<'src, 'run>,
+    context_arena: &'run Arena<RecipeContext<'src, 'run>>,
+    scope_arena: &'run Arena<Scope<'src, 'run>>,
+  ) -> Self {
+    let mut contexts = BTreeMap::new();
+
+    contexts.insert(Vec::new(), &*context_arena.alloc(context));
+
+    Self {
+      errors: Vec::new(),
+      finished: Vec::new(),
+      jobs: Vec::new(),
+      outcomes: Vec::new(),
+      ran: BTreeMap::new(),
+      ran_jobs: Vec::new(),
+      config,
+      context_arena,
+      contexts,
+      dotenv,
+      justfile,
+      scope_arena,
+    }
+  }
+
+  /// Add `recipe`, in the submodule at `module`, invoked with `arguments` from
+  /// the command line. Recipes given on the command line always run, even if
+  /// they have already run as a dependency, but never concurrently with an
+  /// earlier identical invocation.
+  pub(crate) fn add(
+    &mut self,
+    module: &[&'run str],
+    recipe: &'run Recipe<'src>,
+    arguments: &[&str],
+  ) -> RunResult<'src, ()> {
+    let after = self
+      .ran
+      .get(&Self::invocation(module, recipe, arguments))
+      .map(|&index| self.finished[index].clone())
+      .unwrap_or_default();
+
+    self.add_recipe(module, recipe, arguments, after)?;
+
+    Ok(())
+  }
+
+  /// Add a job for `recipe` which runs after `after`, along with jobs for its
+  /// dependencies, returning the new job's index
+  fn add_recipe(
+    &mut self,
+    module: &[&'run str],
+    recipe: &'run Recipe<'src>,
+    arguments: &[&str],
+    mut after: BTreeSet<usize>,
+  ) -> RunResult<'src, usize> {
+    let context = self.context(module)?;
+
+    let (parameters, positional) = Evaluator::evaluate_parameters(
+      self.config,
+      self.dotenv,
+      recipe,
+      arguments,
+      &context.scope,
+      context.settings,
+      context.search,
+    )?;
+
+    let parameters = self.scope_arena.alloc(parameters);
+
+    let locals = self.scope_arena.alloc(Evaluator::evaluate_locals(
+      self.config,
+      self.dotenv,
+      recipe,
+      parameters,
+      context.settings,
+      context.search,
+    )?);
+
+    let mut evaluator = Evaluator::recipe_evaluator(
+      self.config,
+      self.dotenv,
+      locals,
+      context.settings,
+      context.search,
+      recipe.working_directory(context.settings, context.search),
+    );
+
+    for prior in self.add_dependencies(module, recipe.priors(), &mut evaluator, &BTreeSet::new())? {
+      after.extend(self.finished[prior].iter().cloned());
+    }
+
+    let index = self.jobs.len();
+
+    let invocation = Self::invocation(module, recipe, arguments);
+
+    self.jobs.push(Job {
+      invocation: invocation.clone(),
+      dependencies: after,
+      scope: locals,
+      context,
+      positional,
+      recipe,
+    });
+
+    self
+      .finished
+      .push(iter::once(index).collect::<BTreeSet<usize>>());
+
+    self.ran.insert(invocation, index);
+
+    if self.config.jobs <= 1 {
+      self.run_job(index)?;
+    }
+
+    let body = iter::once(index).collect::<BTreeSet<usize>>();
+
+    for subsequent in self.add_dependencies(module, recipe.subsequents(), &mut evaluator, &body)? {
+      let finished = self.finished[subsequent].clone();
+      self.finished[index].extend(finished);
+    }
+
+    Ok(index)
+  }
+
+  /// Run the job at `index` serially, unless a job it depends on didn't pass
+  fn run_job(&mut self, index: usize) -> RunResult<'src, ()> {
+    self.outcomes.push(Outcome::Skipped);
+    self.ran_jobs.push(false);
+
+    let job = &self.jobs[index];
+
+    if !Self::dependencies_passed(job, &self.outcomes) {
+      return Ok(());
+    }
+
+    let dependencies_ran = job
+      .dependencies
+      .iter()
+      .any(|&dependency| self.ran_jobs[dependency]);
+
+    match job.run(self.dotenv, dependencies_ran, None) {
+      Ok(job_ran) => {
+        self.ran_jobs[index] = job_ran;
+        self.outcomes[index] = Outcome::Passed;
+      },
+      Err(error) => {
+        if !self.config.keep_going {
+          return Err(error);
+        }
+        self.outcomes[index] = Outcome::Failed;
+        self.errors.push(error);
+      },
+    }
+
+    Ok(())
+  }
+
+  /// Add jobs for `dependencies` of a recipe in the submodule at `module`,
+  /// evaluating their arguments with `evaluator` and reusing the jobs of those
+  /// that have already been added. New jobs run after `after`. Returns the
+  /// indices of the dependencies' jobs.
+  fn add_dependencies(
+    &mut self,
+    module: &[&'run str],
+    dependencies: &'run [Dependency<'src>],
+    evaluator: &mut Evaluator<'src, '_>,
+    after: &BTreeSet<usize>,
+  ) -> RunResult<'src, Vec<usize>> {
+    let mut indices = Vec::new();
+
+    for dependency in dependencies {
+      let dependency_module = module
+        .iter()
+        .cloned()
+        .chain(dependency.module())
+        .collect::<Vec<&str>>();
+
+      let mut arguments = Vec::new();
+      for argument in &dependency.arguments {
+        arguments.push(evaluator.evaluate_expression(argument)?);
+      }
+
+      let arguments = arguments.iter().map(String::as_ref).collect::<Vec<&str>>();
+
+      let invocation = Self::invocation(&dependency_module, &dependency.recipe, &arguments);
+
+      let index = match self.ran.get(&invocation) {
+        Some(&index) => index,
+        None => self.add_recipe(
+          &dependency_module,
+          &dependency.recipe,
+          &arguments,
+          after.clone(),
+        )?,
+      };
+
+      indices.push(index);
+    }
+
+    Ok(indices)
+  }
+
+  /// Get the context for running recipes in the submodule at `module`,
+  /// evaluating its assignments the first time it is needed
+  fn context(&mut self, module: &[&'run str]) -> RunResult<'src, &'run RecipeContext<'src, 'run>> {
+    if let Some(context) = self.contexts.get(module) {
+      return Ok(context);
+    }
+
+    let justfile = self
+      .justfile
+      .get_module(module.iter().cloned())
+      .expect("Recipe module path did not resolve");
+
+    let search = justfile
+      .search
+      .as_ref()
+      .expect("Submodule justfile missing search");
+
+    let scope = Evaluator::evaluate_assignments(
+      &justfile.assignments,
+      self.config,
+      self.dotenv,
+      Scope::new(),
+      &justfile.settings,
+      search,
+    )?;
+
+    let context = &*self.context_arena.alloc(RecipeContext {
+      module: module.to_vec(),
+      settings: &justfile.settings,
+      config: self.config,
+      scope,
+      search,
+    });
+
+    self.contexts.insert(module.to_vec(), context);
+
+    Ok(context)
+  }
+
+  /// The invocation of `recipe` in the submodule at `module` with `arguments`,
+  /// e.g. `["foo::bar", "baz"]`
+  fn invocation(module: &[&str], recipe: &Recipe, arguments: &[&str]) -> Vec<String> {
+    let name = module
+      .iter()
+      .cloned()
+      .chain(iter::once(recipe.name()))
+      .collect::<Vec<&str>>()
+      .join("::");
+
+    iter::once(name)
+      .chain(arguments.iter().cloned().map(str::to_owned))
+      .collect()
+  }
+
+  /// Run the plan's jobs, skipping those that are up to date. Unless `--jobs`
+  /// is greater than one, jobs have already run as they were added, and only
+  /// the result is reported. Otherwise, jobs whose dependencies have finished
+  /// are run concurrently, up to the `--jobs` limit. If a job fails, no new
+  /// jobs are started, running jobs are killed, and the first error is
+  /// returned. With `--keep-going`, jobs whose dependencies succeeded keep
+  /// running, the rest are skipped, and a summary is printed once all jobs
+  /// have finished.
+  pub(crate) fn run(mut self) -> RunResult<'src, ()> {
+    if self.config.jobs <= 1 {
+      let errors = mem::take(&mut self.errors);
+      return self.finish(&self.outcomes, errors);
+    }
+
+    let keep_going = self.config.keep_going;
+
+    // Whether each job's recipe ran, rather than being skipped as up to date
+    let mut ran = vec![false; self.jobs.len()];
+
+    // Jobs that never run, because a job they depend on failed, or because
+    // another job failed without `--keep-going`, are skipped
+    let mut outcomes = vec![Outcome::Skipped; self.jobs.len()];
+
+    let mut errors = Vec::new();
+
+    let mut dependents = vec![Vec::new(); self.jobs.len()];
+    let mut pending = Vec::new();
+    let mut ready = BTreeSet::new();
+
+    for (index, job) in self.jobs.iter().enumerate() {
+      for &dependency in &job.dependencies {
+        dependents[dependency].push(index);
+      }
+
+      if job.dependencies.is_empty() {
+        ready.insert(index);
+      }
+
+      pending.push(job.dependencies.clone());
+    }
+
+    // Set when a job fails without `--keep-going`, to kill running jobs
+    let cancel = &AtomicBool::new(false);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+      let mut running = 0;
+
+      loop {
+        while (keep_going || errors.is_empty())
+          && running < self.config.jobs
+          && !cancel.load(Ordering::SeqCst)
+        {
+          let index = match ready.pop_first() {
+            Some(index) => index,
+            None => break,
+          };
+
+          let job = &self.jobs[index];
+          let dependencies_ran = job.dependencies.iter().any(|&dependency| ran[dependency]);
+          let sender = sender.clone();
+          let dotenv = self.dotenv;
+
+          scope.spawn(move || {
+            sender
+              .send((index, job.run(dotenv, dependencies_ran, Some(cancel))))
+              .expect("Job result receiver dropped");
+          });
+
+          running += 1;
+        }
+
+        if running == 0 {
+          break;
+        }
+
+        let (index, result) = receiver.recv().expect("Job result senders dropped");
+
+        running -= 1;
+
+        match result {
+          Ok(job_ran) => {
+            ran[index] = job_ran;
+            outcomes[index] = Outcome::Passed;
+          },
+          Err(error) => {
+            if !keep_going {
+              cancel.store(true, Ordering::SeqCst);
+            }
+            outcomes[index] = Outcome::Failed;
+            errors.push(error);
+          },
+        }
+
+        // Jobs whose dependencies have all finished are ready if they all
+        // passed, and are otherwise skipped, which in turn finishes their
+        // own dependents
+        let mut finished = vec![index];
+
+        while let Some(index) = finished.pop() {
+          for &dependent in &dependents[index] {
+            pending[dependent].remove(&index);
+            if pending[dependent].is_empty() {
+              if Self::dependencies_passed(&self.jobs[dependent], &outcomes) {
+                ready.insert(dependent);
+              } else {
+                finished.push(dependent);
+              }
+            }
+          }
+        }
+      }
+    });
+
+    self.finish(&outcomes, errors)
+  }
+
+  fn dependencies_passed(job: &Job, outcomes: &[Outcome]) -> bool {
+    job
+      .dependencies
+      .iter()
+      .all(|&dependency| outcomes[dependency] == Outcome::Passed)
+  }
+
+  /// Print the `--keep-going` summary, and return the first error, or, with
+  /// `--keep-going`, all errors
+  fn finish(
+    &self,
+    outcomes: &[Outcome],
+    mut errors: Vec<RuntimeError<'src>>,
+  ) -> RunResult<'src, ()> {
+    if !self.config.keep_going {
+      return match errors.into_iter().next() {
+        Some(error) => Err(error),
+        None => Ok(()),
+      };
+    }
+
+    if !self.config.verbosity.quiet() {
+      let names = self
+        .jobs
+        .iter()
+        .map(|job| job.invocation.join(" "))
+        .collect::<Vec<String>>();
+
+      let width = names
+        .iter()
+        .map(|name| UnicodeWidthStr::width(name.as_str()))
+        .max()
+        .unwrap_or(0);
+
+      let color = self.config.color.stderr();
+
+      eprintln!(
+        "{}===> Summary{}",
+        color.banner().prefix(),
+        color.banner().suffix()
+      );
+
+      for (name, outcome) in names.iter().zip(outcomes) {
+        eprintln!(
+          "{}{} {}",
+          name,
+          " ".repeat(width - UnicodeWidthStr::width(name.as_str())),
+          outcome.color(color).paint(&outcome.to_string()),
+        );
+      }
+    }
+
+    match errors.len() {
+      0 => Ok(()),
+      1 => Err(errors.pop().unwrap()),
+      _ => Err(RuntimeError::Multiple { errors }),
+    }
+  }
+}
@@ -6,18 +6,87 @@ pub(crate) struct Platform;
 impl PlatformInterface for Platform {
   fn make_shebang_command(
     path: &Path,
-    working_directory: &Path,
+    working_directory: Option<&Path>,
     _command: &str,
     _argument: Option<&str>,
   ) -> Result<Command, OutputError> {
     // shebang scripts can be executed directly on unix
     let mut cmd = Command::new(path);
 
-    cmd.current_dir(working_directory);
+    if let Some(working_directory) = working_directory {
+      cmd.current_dir(working_directory);
+    }
 
     Ok(cmd)
   }
 
+  fn spawn_process_group(command: &mut Command, foreground: bool) -> io::Result<Child> {
+    use std::os::unix::process::CommandExt;
+
+    if atty::is(atty::Stream::Stdin) {
+      if !foreground {
+        return command.spawn();
+      }
+
+      // SAFETY: The closure runs in the child between `fork` and `exec`, where
+      // only async-signal-safe functions may be called, and only calls those,
+      // none of which touch memory other than their arguments
+      unsafe {
+        command.pre_exec(|| {
+          // The child has already been put in its own group, and only takes
+          // the terminal if its parent's group has it
+          if libc::tcgetpgrp(libc::STDIN_FILENO) == libc::getpgid(libc::getppid()) {
+            Self::set_foreground(libc::getpgrp())
+          } else {
+            Ok(())
+          }
+        });
+      }
+    }
+
+    command.process_group(0);
+
+    command.spawn()
+  }
+
+  fn reclaim_terminal(child: &Child) -> io::Result<bool> {
+    let pid = Self::pid(child)?;
+
+    // SAFETY: `tcgetpgrp` only reads its integer argument, and returns -1 if
+    // stdin isn't a terminal
+    if unsafe { libc::tcgetpgrp(libc::STDIN_FILENO) } != pid {
+      return Ok(false);
+    }
+
+    // SAFETY: `getpgrp` takes no arguments and always succeeds
+    Self::set_foreground(unsafe { libc::getpgrp() })?;
+
+    Ok(true)
+  }
+
+  fn kill_process_group(child: &mut Child) -> io::Result<()> {
+    let pid = Self::pid(child)?;
+
+    // If the child didn't start its own group, it shares ours, so only kill
+    // the child
+    //
+    // SAFETY: `getpgid` only reads its integer argument, and returns -1 if
+    // `pid` doesn't name a process
+    if unsafe { libc::getpgid(pid) } != pid {
+      return child.kill();
+    }
+
+    // Signalling the negated process ID signals every process in the group
+    //
+    // SAFETY: `kill` only reads its integer arguments. `child` hasn't been
+    // waited on, so `pid` can't have been reused by an unrelated process group.
+    if unsafe { libc::kill(-pid, libc::SIGKILL) } == 0 {
+      Ok(())
+    } else {
+      Err(io::Error::last_os_error())
+    }
+  }
+
   fn set_execute_permission(path: &Path) -> Result<(), io::Error> {
     use std::os::unix::fs::PermissionsExt;
 
@@ -45,11 +114,52 @@ impl PlatformInterface for Platform {
   }
 }
 
+#[cfg(unix)]
+impl Platform {
+  fn pid(child: &Child) -> io::Result<libc::pid_t> {
+    use std::convert::TryFrom;
+
+    libc::pid_t::try_from(child.id())
+      .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))
+  }
+
+  /// Put `group` in the foreground of the terminal on stdin. Processes
+  /// outside of the foreground group are sent `SIGTTOU` when they do so,
+  /// which would stop them, so it is blocked in the meantime. Only calls
+  /// async-signal-safe functions, so it may be called between `fork` and
+  /// `exec`.
+  fn set_foreground(group: libc::pid_t) -> io::Result<()> {
+    use std::{mem::MaybeUninit, ptr};
+
+    // SAFETY: `sigemptyset` initializes `set`, and `pthread_sigmask`
+    // initializes `previous`, before they are read. The other functions only
+    // read their integer arguments.
+    unsafe {
+      let mut set = MaybeUninit::<libc::sigset_t>::uninit();
+      let mut previous = MaybeUninit::<libc::sigset_t>::uninit();
+
+      libc::sigemptyset(set.as_mut_ptr());
+      libc::sigaddset(set.as_mut_ptr(), libc::SIGTTOU);
+      libc::pthread_sigmask(libc::SIG_BLOCK, set.as_ptr(), previous.as_mut_ptr());
+
+      let result = if libc::tcsetpgrp(libc::STDIN_FILENO, group) == 0 {
+        Ok(())
+      } else {
+        Err(io::Error::last_os_error())
+      };
+
+      libc::pthread_sigmask(libc::SIG_SETMASK, previous.as_ptr(), ptr::null_mut());
+
+      result
+    }
+  }
+}
+
 #[cfg(windows)]
 impl PlatformInterface for Platform {
   fn make_shebang_command(
     path: &Path,
-    working_directory: &Path,
+    working_directory: Option<&Path>,
     command: &str,
     argument: Option<&str>,
   ) -> Result<Command, OutputError> {
@@ -57,7 +167,9 @@ impl PlatformInterface for Platform {
     let command = if command.contains('/') {
       // …translate path to the interpreter from unix style to windows style.
       let mut cygpath = Command::new("cygpath");
-      cygpath.current_dir(working_directory);
+      if let Some(working_directory) = working_directory {
+        cygpath.current_dir(working_directory);
+      }
       cygpath.arg("--windows");
       cygpath.arg(command);
 
@@ -69,7 +181,9 @@ impl PlatformInterface for Platform {
 
     let mut cmd = Command::new(command.as_ref());
 
-    cmd.current_dir(working_directory);
+    if let Some(working_directory) = working_directory {
+      cmd.current_dir(working_directory);
+    }
 
     if let Some(argument) = argument {
       cmd.arg(argument);
@@ -79,6 +193,18 @@ impl PlatformInterface for Platform {
     Ok(cmd)
   }
 
+  fn spawn_process_group(command: &mut Command, _foreground: bool) -> io::Result<Child> {
+    command.spawn()
+  }
+
+  fn reclaim_terminal(_child: &Child) -> io::Result<bool> {
+    Ok(false)
+  }
+
+  fn kill_process_group(child: &mut Child) -> io::Result<()> {
+    child.kill()
+  }
+
   fn set_execute_permission(_path: &Path) -> Result<(), io::Error> {
     // it is not necessary to set an execute permission on a script on windows, so
     // this is a nop
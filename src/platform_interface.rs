@@ -2,14 +2,31 @@ use crate::common::*;
 
 pub(crate) trait PlatformInterface {
   /// Construct a command equivalent to running the script at `path` with the
-  /// shebang line `shebang`
+  /// shebang line `shebang`, in `working_directory` if it is not `None`
   fn make_shebang_command(
     path: &Path,
-    working_directory: &Path,
+    working_directory: Option<&Path>,
     command: &str,
     argument: Option<&str>,
   ) -> Result<Command, OutputError>;
 
+  /// Spawn `command` in a new process group, so that it can be killed along
+  /// with any processes it starts. Processes outside of the terminal's
+  /// foreground process group are stopped if they read from it, so if stdin
+  /// is a terminal and `foreground` is true, the new group is put in the
+  /// foreground if the current group is, and must be taken back with
+  /// `reclaim_terminal`. If stdin is a terminal and `foreground` is false,
+  /// `command` is spawned in the current process group instead.
+  fn spawn_process_group(command: &mut Command, foreground: bool) -> io::Result<Child>;
+
+  /// Put the current process group back in the terminal's foreground if
+  /// `child`'s process group is in it, returning whether it was
+  fn reclaim_terminal(child: &Child) -> io::Result<bool>;
+
+  /// Kill `child` and the other processes in its process group, or only
+  /// `child` if it was spawned in the current process group
+  fn kill_process_group(child: &mut Child) -> io::Result<()>;
+
   /// Set the execute permission on the file pointed to by `path`
   fn set_execute_permission(path: &Path) -> Result<(), io::Error>;
 
@@ -25,11 +25,19 @@ fn error_from_signal(
 /// A recipe, e.g. `foo: bar baz`
 #[derive(PartialEq, Debug)]
 pub(crate) struct Recipe<'src, D = Dependency<'src>> {
+  pub(crate) attributes:   BTreeSet<Attribute<'src>>,
   pub(crate) dependencies: Vec<D>,
   pub(crate) doc:          Option<&'src str>,
   pub(crate) body:         Vec<Line<'src>>,
+  /// Line number of the first line of the body, following any recipe-local
+  /// assignments
+  pub(crate) body_line:    usize,
+  pub(crate) locals:       Vec<Assignment<'src>>,
   pub(crate) name:         Name<'src>,
   pub(crate) parameters:   Vec<Parameter<'src>>,
+  /// Number of dependencies that run before the recipe, the remainder run
+  /// after it succeeds
+  pub(crate) priors:       usize,
   pub(crate) private:      bool,
   pub(crate) quiet:        bool,
   pub(crate) shebang:      bool,
@@ -64,19 +72,207 @@ impl<'src, D> Recipe<'src, D> {
     self.name.line
   }
 
+  /// Dependencies that run before the recipe
+  pub(crate) fn priors(&self) -> &[D] {
+    &self.dependencies[..self.priors]
+  }
+
+  /// Dependencies that run after the recipe succeeds
+  pub(crate) fn subsequents(&self) -> &[D] {
+    &self.dependencies[self.priors..]
+  }
+
   pub(crate) fn public(&self) -> bool {
     !self.private
   }
 
+  /// Directory in which to run the recipe, or `None` if it should run in the
+  /// invocation directory
+  pub(crate) fn working_directory(&self, settings: &Settings, search: &Search) -> Option<PathBuf> {
+    if self.attributes.contains(&Attribute::NoCd) {
+      return None;
+    }
+
+    let working_directory = settings.working_directory(search);
+
+    for attribute in &self.attributes {
+      if let Attribute::WorkingDirectory(path) = attribute {
+        return Some(working_directory.join(path.cooked.as_ref()));
+      }
+    }
+
+    Some(working_directory)
+  }
+
+  /// The retry count and initial backoff given by the recipe's `[retry]`
+  /// attribute
+  fn retry(&self) -> Option<(u32, Duration)> {
+    self
+      .attributes
+      .iter()
+      .find_map(|attribute| match attribute {
+        // Retry counts and backoffs are validated when the justfile is compiled
+        Attribute::Retry(arguments) =>
+          Some((arguments[0].cooked.parse().ok()?, match arguments.get(1) {
+            Some(backoff) => humantime::parse_duration(&backoff.cooked).ok()?,
+            None => Duration::from_secs(0),
+          })),
+        _ => None,
+      })
+  }
+
+  /// The duration given by the recipe's `[timeout]` attribute
+  pub(crate) fn timeout(&self) -> Option<Duration> {
+    self
+      .attributes
+      .iter()
+      .find_map(|attribute| match attribute {
+        // Durations are validated when the justfile is compiled
+        Attribute::Timeout(duration) => humantime::parse_duration(&duration.cooked).ok(),
+        _ => None,
+      })
+  }
+
+  /// Glob patterns given by the recipe's `[watch]` attributes
+  pub(crate) fn watch_patterns(&self) -> impl Iterator<Item = &str> {
+    self
+      .attributes
+      .iter()
+      .filter_map(|attribute| match attribute {
+        Attribute::Watch(patterns) => Some(patterns),
+        _ => None,
+      })
+      .flatten()
+      .map(|pattern| pattern.cooked.as_ref())
+  }
+
+  /// Whether the recipe has `[outputs]` which all exist and are newer than
+  /// all files matching its `[inputs]`. If anything can't be checked, the
+  /// recipe is considered out of date, so that it runs.
+  pub(crate) fn is_up_to_date(&self, context: &RecipeContext) -> bool {
+    let mut inputs: &[StringLiteral] = &[];
+    let mut outputs: &[StringLiteral] = &[];
+
+    for attribute in &self.attributes {
+      match attribute {
+        Attribute::Inputs(patterns) => inputs = patterns,
+        Attribute::Outputs(paths) => outputs = paths,
+        _ => {},
+      }
+    }
+
+    if outputs.is_empty() {
+      return false;
+    }
+
+    let directory = self
+      .working_directory(context.settings, context.search)
+      .unwrap_or_else(|| context.config.invocation_directory.clone());
+
+    let modified = |path: &Path| {
+      fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+    };
+
+    // Missing outputs have no modification time, so the recipe is out of date
+    let oldest_output = match outputs
+      .iter()
+      .map(|output| modified(&directory.join(output.cooked.as_ref())))
+      .collect::<Option<Vec<SystemTime>>>()
+      .and_then(|modified| modified.into_iter().min())
+    {
+      Some(oldest_output) => oldest_output,
+      None => return false,
+    };
+
+    let directory = match directory.to_str() {
+      Some(directory) => directory,
+      None => return false,
+    };
+
+    for input in inputs {
+      let pattern = if Path::new(input.cooked.as_ref()).is_absolute() {
+        input.cooked.to_string()
+      } else {
+        format!("{}/{}", glob::Pattern::escape(directory), input.cooked)
+      };
+
+      let paths = match glob::glob(&pattern) {
+        Ok(paths) => paths,
+        Err(_) => return false,
+      };
+
+      for path in paths {
+        match path.ok().and_then(|path| modified(&path)) {
+          Some(input_modified) =>
+            if input_modified > oldest_output {
+              return false;
+            },
+          None => return false,
+        }
+      }
+    }
+
+    true
+  }
+
+  fn print_exit_message(&self) -> bool {
+    !self.attributes.contains(&Attribute::NoExitMessage)
+  }
+
+  /// Run the recipe, returning whether it ran, rather than being skipped
+  /// because it was cached
   pub(crate) fn run<'run>(
     &self,
     context: &RecipeContext<'src, 'run>,
     dotenv: &BTreeMap<String, String>,
     scope: Scope<'src, 'run>,
     search: &'run Search,
-  ) -> RunResult<'src, ()> {
+    positional: &[String],
+    cancel: Option<&AtomicBool>,
+  ) -> RunResult<'src, bool> {
     let config = &context.config;
 
+    let working_directory = self.working_directory(context.settings, search);
+
+    let mut evaluator = Evaluator::recipe_evaluator(
+      context.config,
+      dotenv,
+      &scope,
+      context.settings,
+      search,
+      working_directory.clone(),
+    );
+
+    // The lines of `[cache]` recipes are evaluated up front, since they are
+    // part of the cache key
+    let mut cache = None;
+    let mut evaluated_lines = None;
+
+    if !config.no_cache && self.attributes.contains(&Attribute::Cache) {
+      let lines = self.evaluate_lines(&mut evaluator)?;
+
+      let recipe_cache = Cache::new(context, self, dotenv, &scope, &lines, positional);
+
+      if recipe_cache.hit() {
+        if config.verbosity.loquacious() {
+          let color = config.color.stderr().banner();
+          eprintln!(
+            "{}===> Recipe `{}` is cached{}",
+            color.prefix(),
+            self.name,
+            color.suffix()
+          );
+        }
+
+        return Ok(false);
+      }
+
+      cache = Some(recipe_cache);
+      evaluated_lines = Some(lines);
+    }
+
     if config.verbosity.loquacious() {
       let color = config.color.stderr().banner();
       eprintln!(
@@ -87,14 +283,19 @@ impl<'src, D> Recipe<'src, D> {
       );
     }
 
-    let mut evaluator =
-      Evaluator::recipe_evaluator(context.config, dotenv, &scope, context.settings, search);
+    // The recipe's commands are killed if they are still running once the
+    // recipe's timeout, or the `--timeout` default, has elapsed
+    let start = Instant::now();
+    let deadline = self
+      .timeout()
+      .or(config.timeout)
+      .map(|timeout| start + timeout);
 
     if self.shebang {
-      let mut evaluated_lines = vec![];
-      for line in &self.body {
-        evaluated_lines.push(evaluator.evaluate_line(line, false)?);
-      }
+      let evaluated_lines = match evaluated_lines {
+        Some(evaluated_lines) => evaluated_lines,
+        None => self.evaluate_lines(&mut evaluator)?,
+      };
 
       if config.dry_run || self.quiet {
         for line in &evaluated_lines {
@@ -103,7 +304,7 @@ impl<'src, D> Recipe<'src, D> {
       }
 
       if config.dry_run {
-        return Ok(());
+        return Ok(true);
       }
 
       let tmp = tempfile::Builder::new()
@@ -126,7 +327,7 @@ impl<'src, D> Recipe<'src, D> {
         text += "\n";
         // add blank lines so that lines in the generated script have the same line
         // number as the corresponding lines in the justfile
-        for _ in 1..(self.line_number() + 2) {
+        for _ in 1..(self.body_line + 1) {
           text += "\n"
         }
         for line in &evaluated_lines[1..] {
@@ -165,27 +366,35 @@ impl<'src, D> Recipe<'src, D> {
       })?;
 
       // create a command to run the script
-      let mut command = Platform::make_shebang_command(
-        &path,
-        &context.search.working_directory,
-        interpreter,
-        argument,
-      )
-      .map_err(|output_error| RuntimeError::Cygpath {
-        recipe: self.name(),
-        output_error,
-      })?;
+      let mut command =
+        Platform::make_shebang_command(&path, working_directory.as_deref(), interpreter, argument)
+          .map_err(|output_error| RuntimeError::Cygpath {
+            recipe: self.name(),
+            output_error,
+          })?;
 
-      command.export(dotenv, &scope);
+      if context.settings.positional_arguments {
+        command.args(positional);
+      }
+
+      command.export(context.settings, dotenv, &scope);
 
       // run it!
-      match InterruptHandler::guard(|| command.status()) {
-        Ok(exit_status) =>
+      match self.status(config, &mut command, None, true, cancel, deadline) {
+        Ok(None) => {
+          return Err(RuntimeError::Timeout {
+            recipe:      self.name(),
+            line_number: None,
+            elapsed:     start.elapsed(),
+          });
+        },
+        Ok(Some(exit_status)) =>
           if let Some(code) = exit_status.code() {
             if code != 0 {
               return Err(RuntimeError::Code {
                 recipe: self.name(),
                 line_number: None,
+                print_message: self.print_exit_message(),
                 code,
               });
             }
@@ -202,8 +411,9 @@ impl<'src, D> Recipe<'src, D> {
         },
       };
     } else {
+      let mut evaluated_lines = evaluated_lines.map(Vec::into_iter);
       let mut lines = self.body.iter().peekable();
-      let mut line_number = self.line_number() + 1;
+      let mut line_number = self.body_line;
       loop {
         if lines.peek().is_none() {
           break;
@@ -221,7 +431,15 @@ impl<'src, D> Recipe<'src, D> {
           }
           let line = lines.next().unwrap();
           line_number += 1;
-          evaluated += &evaluator.evaluate_line(line, continued)?;
+          evaluated += &match &mut evaluated_lines {
+            Some(evaluated_lines) =>
+              evaluated_lines
+                .next()
+                .ok_or_else(|| RuntimeError::Internal {
+                  message: "evaluated_lines ended early".to_string(),
+                })?,
+            None => evaluator.evaluate_line(line, continued)?,
+          };
           if line.is_continuation() {
             continued = true;
             evaluated.pop();
@@ -256,24 +474,46 @@ impl<'src, D> Recipe<'src, D> {
 
         let mut cmd = context.settings.shell_command(config);
 
-        cmd.current_dir(&context.search.working_directory);
+        if let Some(working_directory) = &working_directory {
+          cmd.current_dir(working_directory);
+        }
 
         cmd.arg(command);
 
+        if context.settings.positional_arguments {
+          cmd.arg(self.name.lexeme());
+          cmd.args(positional);
+        }
+
         if config.verbosity.quiet() {
           cmd.stderr(Stdio::null());
           cmd.stdout(Stdio::null());
         }
 
-        cmd.export(dotenv, &scope);
-
-        match InterruptHandler::guard(|| cmd.status()) {
-          Ok(exit_status) =>
+        cmd.export(context.settings, dotenv, &scope);
+
+        match self.status(
+          config,
+          &mut cmd,
+          Some(line_number),
+          !infallable_command,
+          cancel,
+          deadline,
+        ) {
+          Ok(None) => {
+            return Err(RuntimeError::Timeout {
+              recipe:      self.name(),
+              line_number: Some(line_number),
+              elapsed:     start.elapsed(),
+            });
+          },
+          Ok(Some(exit_status)) =>
             if let Some(code) = exit_status.code() {
               if code != 0 && !infallable_command {
                 return Err(RuntimeError::Code {
                   recipe: self.name(),
                   line_number: Some(line_number),
+                  print_message: self.print_exit_message(),
                   code,
                 });
               }
@@ -293,7 +533,102 @@ impl<'src, D> Recipe<'src, D> {
         };
       }
     }
-    Ok(())
+
+    if let Some(cache) = cache {
+      if !config.dry_run {
+        cache.save(self.name())?;
+      }
+    }
+
+    Ok(true)
+  }
+
+  /// Run `command` to completion with `InterruptHandler::status`. If `retry`
+  /// is true and the command fails with a non-zero exit code, it is run again
+  /// according to the recipe's `[retry]` attribute, and each retry is
+  /// reported.
+  fn status(
+    &self,
+    config: &Config,
+    command: &mut Command,
+    line_number: Option<usize>,
+    retry: bool,
+    cancel: Option<&AtomicBool>,
+    deadline: Option<Instant>,
+  ) -> io::Result<Option<ExitStatus>> {
+    let (retries, mut backoff) = match self.retry() {
+      Some(retries_and_backoff) if retry => retries_and_backoff,
+      _ => return InterruptHandler::status(command, cancel, deadline),
+    };
+
+    // Whether to stop retrying, because `cancel` is set, an interrupt was
+    // received, or `deadline` has passed, in which case the recipe timed out
+    let stop = |status| {
+      if matches!(deadline, Some(deadline) if Instant::now() >= deadline) {
+        Some(None)
+      } else if InterruptHandler::cancelled(cancel) {
+        Some(status)
+      } else {
+        None
+      }
+    };
+
+    for attempt in 1..=retries {
+      let status = InterruptHandler::status(command, cancel, deadline)?;
+
+      // Only failures with an exit code are retried, not timeouts, signals,
+      // or cancellation
+      let code = match status.and_then(|exit_status| exit_status.code()) {
+        Some(code) if code != 0 => code,
+        _ => return Ok(status),
+      };
+
+      if let Some(status) = stop(status) {
+        return Ok(status);
+      }
+
+      if !config.verbosity.quiet() {
+        let color = config.color.stderr().banner();
+        let line = match line_number {
+          Some(n) => format!(" on line {}", n),
+          None => String::new(),
+        };
+        eprintln!(
+          "{}===> Recipe `{}` failed{} with exit code {}, retrying ({} of {})...{}",
+          color.prefix(),
+          self.name,
+          line,
+          code,
+          attempt,
+          retries,
+          color.suffix()
+        );
+      }
+
+      InterruptHandler::sleep(backoff, cancel, deadline);
+
+      if let Some(status) = stop(status) {
+        return Ok(status);
+      }
+
+      backoff = backoff.saturating_mul(2);
+    }
+
+    InterruptHandler::status(command, cancel, deadline)
+  }
+
+  /// Evaluate every line of the body. Continuation lines of linewise recipes
+  /// are evaluated as they would be when run.
+  fn evaluate_lines(&self, evaluator: &mut Evaluator<'src, '_>) -> RunResult<'src, Vec<String>> {
+    let mut evaluated_lines = Vec::new();
+    let mut continued = false;
+
+    for line in &self.body {
+      evaluated_lines.push(evaluator.evaluate_line(line, continued)?);
+      continued = !self.shebang && line.is_continuation();
+    }
+
+    Ok(evaluated_lines)
   }
 }
 
@@ -309,6 +644,10 @@ impl<'src> Display for Recipe<'src> {
       writeln!(f, "# {}", doc)?;
     }
 
+    for attribute in &self.attributes {
+      writeln!(f, "[{}]", attribute)?;
+    }
+
     if self.quiet {
       write!(f, "@{}", self.name)?;
     } else {
@@ -319,10 +658,23 @@ impl<'src> Display for Recipe<'src> {
       write!(f, " {}", parameter)?;
     }
     write!(f, ":")?;
-    for dependency in &self.dependencies {
+    for (i, dependency) in self.dependencies.iter().enumerate() {
+      if i == self.priors {
+        write!(f, " &&")?;
+      }
       write!(f, " {}", dependency)?;
     }
 
+    for local in &self.locals {
+      write!(
+        f,
+        "\n    {} {} := {}",
+        Keyword::Let.lexeme(),
+        local.name,
+        local.value
+      )?;
+    }
+
     for (i, line) in self.body.iter().enumerate() {
       if i == 0 {
         writeln!(f)?;
@@ -2,6 +2,8 @@ use crate::common::*;
 
 pub(crate) struct RecipeContext<'src: 'run, 'run> {
   pub(crate) config:   &'run Config,
+  /// Path of the submodule whose recipes run in this context
+  pub(crate) module:   Vec<&'run str>,
   pub(crate) scope:    Scope<'src, 'run>,
   pub(crate) search:   &'run Search,
   pub(crate) settings: &'run Settings<'src>,
@@ -4,19 +4,22 @@ use CompilationErrorKind::*;
 
 pub(crate) struct RecipeResolver<'src: 'run, 'run> {
   unresolved_recipes: Table<'src, UnresolvedRecipe<'src>>,
-  resolved_recipes:   Table<'src, Rc<Recipe<'src>>>,
+  resolved_recipes:   Table<'src, Arc<Recipe<'src>>>,
   assignments:        &'run Table<'src, Assignment<'src>>,
+  modules:            &'run BTreeMap<&'src str, Justfile<'src>>,
 }
 
 impl<'src: 'run, 'run> RecipeResolver<'src, 'run> {
   pub(crate) fn resolve_recipes(
     unresolved_recipes: Table<'src, UnresolvedRecipe<'src>>,
     assignments: &Table<'src, Assignment<'src>>,
-  ) -> CompilationResult<'src, Table<'src, Rc<Recipe<'src>>>> {
+    modules: &BTreeMap<&'src str, Justfile<'src>>,
+  ) -> CompilationResult<'src, Table<'src, Arc<Recipe<'src>>>> {
     let mut resolver = RecipeResolver {
       resolved_recipes: empty(),
       unresolved_recipes,
       assignments,
+      modules,
     };
 
     while let Some(unresolved) = resolver.unresolved_recipes.pop() {
@@ -27,15 +30,21 @@ impl<'src: 'run, 'run> RecipeResolver<'src, 'run> {
       for parameter in &recipe.parameters {
         if let Some(expression) = &parameter.default {
           for variable in expression.variables() {
-            resolver.resolve_variable(&variable, &[])?;
+            resolver.resolve_variable(&variable, &[], &[])?;
           }
         }
       }
 
+      for (i, local) in recipe.locals.iter().enumerate() {
+        for variable in local.value.variables() {
+          resolver.resolve_variable(&variable, &recipe.parameters, &recipe.locals[..i])?;
+        }
+      }
+
       for dependency in &recipe.dependencies {
         for argument in &dependency.arguments {
           for variable in argument.variables() {
-            resolver.resolve_variable(&variable, &recipe.parameters)?;
+            resolver.resolve_variable(&variable, &recipe.parameters, &recipe.locals)?;
           }
         }
       }
@@ -44,7 +53,7 @@ impl<'src: 'run, 'run> RecipeResolver<'src, 'run> {
         for fragment in &line.fragments {
           if let Fragment::Interpolation { expression, .. } = fragment {
             for variable in expression.variables() {
-              resolver.resolve_variable(&variable, &recipe.parameters)?;
+              resolver.resolve_variable(&variable, &recipe.parameters, &recipe.locals)?;
             }
           }
         }
@@ -58,10 +67,12 @@ impl<'src: 'run, 'run> RecipeResolver<'src, 'run> {
     &self,
     variable: &Token<'src>,
     parameters: &[Parameter],
+    locals: &[Assignment],
   ) -> CompilationResult<'src, ()> {
     let name = variable.lexeme();
-    let undefined =
-      !self.assignments.contains_key(name) && !parameters.iter().any(|p| p.name.lexeme() == name);
+    let undefined = !self.assignments.contains_key(name)
+      && !parameters.iter().any(|p| p.name.lexeme() == name)
+      && !locals.iter().any(|local| local.name.lexeme() == name);
 
     if undefined {
       return Err(variable.error(UndefinedVariable { variable: name }));
@@ -74,20 +85,23 @@ impl<'src: 'run, 'run> RecipeResolver<'src, 'run> {
     &mut self,
     stack: &mut Vec<&'src str>,
     recipe: UnresolvedRecipe<'src>,
-  ) -> CompilationResult<'src, Rc<Recipe<'src>>> {
+  ) -> CompilationResult<'src, Arc<Recipe<'src>>> {
     if let Some(resolved) = self.resolved_recipes.get(recipe.name()) {
-      return Ok(Rc::clone(resolved));
+      return Ok(Arc::clone(resolved));
     }
 
     stack.push(recipe.name());
 
-    let mut dependencies: Vec<Rc<Recipe>> = Vec::new();
+    let mut dependencies: Vec<Arc<Recipe>> = Vec::new();
     for dependency in &recipe.dependencies {
       let name = dependency.recipe.lexeme();
 
-      if let Some(resolved) = self.resolved_recipes.get(name) {
+      if !dependency.modules.is_empty() {
+        // dependency is in a submodule, which has already been resolved
+        dependencies.push(self.resolve_module_dependency(&recipe, dependency)?);
+      } else if let Some(resolved) = self.resolved_recipes.get(name) {
         // dependency already resolved
-        dependencies.push(Rc::clone(&resolved));
+        dependencies.push(Arc::clone(&resolved));
       } else if stack.contains(&name) {
         let first = stack[0];
         stack.push(first);
@@ -113,11 +127,42 @@ impl<'src: 'run, 'run> RecipeResolver<'src, 'run> {
       }
     }
 
-    let resolved = Rc::new(recipe.resolve(dependencies)?);
-    self.resolved_recipes.insert(Rc::clone(&resolved));
+    let resolved = Arc::new(recipe.resolve(dependencies)?);
+    self.resolved_recipes.insert(Arc::clone(&resolved));
     stack.pop();
     Ok(resolved)
   }
+
+  fn resolve_module_dependency(
+    &self,
+    recipe: &UnresolvedRecipe<'src>,
+    dependency: &UnresolvedDependency<'src>,
+  ) -> CompilationResult<'src, Arc<Recipe<'src>>> {
+    let mut modules = self.modules;
+    let mut justfile = None;
+
+    for module in &dependency.modules {
+      if let Some(submodule) = modules.get(module.lexeme()) {
+        modules = &submodule.modules;
+        justfile = Some(submodule);
+      } else {
+        return Err(module.error(UnknownModule {
+          recipe: recipe.name(),
+          module: module.lexeme(),
+        }));
+      }
+    }
+
+    justfile
+      .and_then(|justfile| justfile.recipes.get(dependency.recipe.lexeme()))
+      .map(Arc::clone)
+      .ok_or_else(|| {
+        dependency.recipe.error(UnknownDependency {
+          recipe:  recipe.name(),
+          unknown: dependency.recipe.lexeme(),
+        })
+      })
+  }
 }
 
 #[cfg(test)]
@@ -154,6 +199,16 @@ mod tests {
     kind:   UnknownDependency{recipe: "a", unknown: "b"},
   }
 
+  analysis_error! {
+    name:   unknown_dependency_module,
+    input:  "a: b::c",
+    offset: 3,
+    line:   0,
+    column: 3,
+    width:  1,
+    kind:   UnknownModule{recipe: "a", module: "b"},
+  }
+
   analysis_error! {
     name:   unknown_interpolation_variable,
     input:  "x:\n {{   hello}}",
@@ -184,6 +239,26 @@ mod tests {
     kind:   UndefinedVariable{variable: "foo"},
   }
 
+  analysis_error! {
+    name:   unknown_variable_in_local,
+    input:  "a:\n let b := c",
+    offset: 13,
+    line:   1,
+    column: 10,
+    width:  1,
+    kind:   UndefinedVariable{variable: "c"},
+  }
+
+  analysis_error! {
+    name:   local_used_before_definition,
+    input:  "a:\n let b := c\n let c := 'd'",
+    offset: 13,
+    line:   1,
+    column: 10,
+    width:  1,
+    kind:   UndefinedVariable{variable: "c"},
+  }
+
   analysis_error! {
     name:   unknown_variable_in_dependency_argument,
     input:  "bar x:\nfoo: (bar baz)",
@@ -193,4 +268,64 @@ mod tests {
     width:  3,
     kind:   UndefinedVariable{variable: "baz"},
   }
+
+  analysis_error! {
+    name:   unknown_variable_before_string_literal,
+    input:  "foo:\n echo {{ typo + \"x\" }}",
+    offset: 14,
+    line:   1,
+    column: 9,
+    width:  4,
+    kind:   UndefinedVariable{variable: "typo"},
+  }
+
+  analysis_error! {
+    name:   unknown_variable_in_function_argument,
+    input:  "foo:\n echo {{ uppercase(typo) }}",
+    offset: 24,
+    line:   1,
+    column: 19,
+    width:  4,
+    kind:   UndefinedVariable{variable: "typo"},
+  }
+
+  analysis_error! {
+    name:   unknown_variable_in_format_string_with_call,
+    input:  "foo:\n echo {{ f\"{{typo}}-{{os()}}\" }}",
+    offset: 18,
+    line:   1,
+    column: 13,
+    width:  4,
+    kind:   UndefinedVariable{variable: "typo"},
+  }
+
+  analysis_error! {
+    name:   unknown_variable_in_join,
+    input:  "foo:\n echo {{ typo / \"x\" }}",
+    offset: 14,
+    line:   1,
+    column: 9,
+    width:  4,
+    kind:   UndefinedVariable{variable: "typo"},
+  }
+
+  analysis_error! {
+    name:   unknown_variable_in_condition,
+    input:  "foo:\n echo {{ if typo == \"a\" { \"b\" } else { \"c\" } }}",
+    offset: 17,
+    line:   1,
+    column: 12,
+    width:  4,
+    kind:   UndefinedVariable{variable: "typo"},
+  }
+
+  analysis_error! {
+    name:   unknown_variable_in_conjunction,
+    input:  "foo:\n echo {{ if \"a\" =~ \"b\" && typo == \"c\" { \"d\" } else { \"e\" } }}",
+    offset: 31,
+    line:   1,
+    column: 26,
+    width:  4,
+    kind:   UndefinedVariable{variable: "typo"},
+  }
 }
@@ -13,16 +13,23 @@ pub(crate) enum RuntimeError<'src> {
     token:        Token<'src>,
     output_error: OutputError,
   },
+  Cache {
+    recipe:   &'src str,
+    path:     PathBuf,
+    io_error: io::Error,
+  },
   Code {
-    recipe:      &'src str,
-    line_number: Option<usize>,
-    code:        i32,
+    recipe:        &'src str,
+    line_number:   Option<usize>,
+    code:          i32,
+    print_message: bool,
   },
   Cygpath {
     recipe:       &'src str,
     output_error: OutputError,
   },
   Dotenv {
+    path:         PathBuf,
     dotenv_error: dotenv::Error,
   },
   FunctionCall {
@@ -42,11 +49,22 @@ pub(crate) enum RuntimeError<'src> {
     argument: Option<String>,
     io_error: io::Error,
   },
+  RegexCompile {
+    regex_error: regex::Error,
+  },
+  Multiple {
+    errors: Vec<RuntimeError<'src>>,
+  },
   Signal {
     recipe:      &'src str,
     line_number: Option<usize>,
     signal:      i32,
   },
+  Timeout {
+    recipe:      &'src str,
+    line_number: Option<usize>,
+    elapsed:     Duration,
+  },
   TmpdirIoError {
     recipe:   &'src str,
     io_error: io::Error,
@@ -77,9 +95,18 @@ impl<'src> Error for RuntimeError<'src> {
         output_error: OutputError::Code(code),
         ..
       } => code,
+      Self::Multiple { ref errors } => errors.first().map_or(EXIT_FAILURE, Error::code),
       _ => EXIT_FAILURE,
     }
   }
+
+  fn print_message(&self) -> bool {
+    match *self {
+      Self::Code { print_message, .. } => print_message,
+      Self::Multiple { ref errors } => errors.iter().any(Error::print_message),
+      _ => true,
+    }
+  }
 }
 
 impl<'src> RuntimeError<'src> {
@@ -178,6 +205,7 @@ impl<'src> Display for RuntimeError<'src> {
         recipe,
         line_number,
         code,
+        ..
       } =>
         if let Some(n) = line_number {
           write!(
@@ -242,8 +270,26 @@ impl<'src> Display for RuntimeError<'src> {
           )?;
         },
       },
-      Dotenv { dotenv_error } => {
-        writeln!(f, "Failed to load .env: {}", dotenv_error)?;
+      Cache {
+        recipe,
+        path,
+        io_error,
+      } => {
+        writeln!(
+          f,
+          "Failed to write cache file `{}` for recipe `{}`: {}",
+          path.display(),
+          recipe,
+          io_error
+        )?;
+      },
+      Dotenv { path, dotenv_error } => {
+        writeln!(
+          f,
+          "Failed to load environment file `{}`: {}",
+          path.display(),
+          dotenv_error
+        )?;
       },
       FunctionCall { function, message } => {
         writeln!(
@@ -253,6 +299,9 @@ impl<'src> Display for RuntimeError<'src> {
           message
         )?;
       },
+      RegexCompile { regex_error } => {
+        write!(f, "Failed to compile regular expression: {}", regex_error)?;
+      },
       Shebang {
         recipe,
         command,
@@ -272,6 +321,19 @@ impl<'src> Display for RuntimeError<'src> {
             recipe, command, io_error
           )?;
         },
+      Multiple { errors } => {
+        let errors = errors.iter().filter(|error| error.print_message());
+        for (i, error) in errors.enumerate() {
+          if i > 0 {
+            write!(f, "\n{}: ", color.error().paint("error"))?;
+          }
+          if f.alternate() {
+            write!(f, "{:#}", error)?;
+          } else {
+            write!(f, "{}", error)?;
+          }
+        }
+      },
       Signal {
         recipe,
         line_number,
@@ -286,6 +348,27 @@ impl<'src> Display for RuntimeError<'src> {
         } else {
           write!(f, "Recipe `{}` was terminated by signal {}", recipe, signal)?;
         },
+      Timeout {
+        recipe,
+        line_number,
+        elapsed,
+      } =>
+        if let Some(n) = line_number {
+          write!(
+            f,
+            "Recipe `{}` timed out on line {} after {:.1}s",
+            recipe,
+            n,
+            elapsed.as_secs_f64()
+          )?;
+        } else {
+          write!(
+            f,
+            "Recipe `{}` timed out after {:.1}s",
+            recipe,
+            elapsed.as_secs_f64()
+          )?;
+        },
       Unknown {
         recipe,
         line_number,
@@ -395,9 +478,3 @@ impl<'src> Display for RuntimeError<'src> {
     Ok(())
   }
 }
-
-impl<'src> From<dotenv::Error> for RuntimeError<'src> {
-  fn from(dotenv_error: dotenv::Error) -> RuntimeError<'src> {
-    RuntimeError::Dotenv { dotenv_error }
-  }
-}
@@ -5,6 +5,7 @@ use std::path::Component;
 pub(crate) const FILENAME: &str = "justfile";
 const PROJECT_ROOT_CHILDREN: &[&str] = &[".bzr", ".git", ".hg", ".svn", "_darcs"];
 
+#[derive(Debug, PartialEq)]
 pub(crate) struct Search {
   pub(crate) justfile:          PathBuf,
   pub(crate) working_directory: PathBuf,
@@ -111,6 +112,25 @@ impl Search {
     }
   }
 
+  /// Search for a justfile starting in the parent of the directory containing
+  /// this justfile, used by the `fallback` setting
+  pub(crate) fn search_parent_directory(&self) -> SearchResult<Self> {
+    let parent = self
+      .justfile
+      .parent()
+      .and_then(Path::parent)
+      .ok_or(SearchError::NotFound)?;
+
+    let justfile = Self::justfile(parent)?;
+
+    let working_directory = Self::working_directory_from_justfile(&justfile)?;
+
+    Ok(Self {
+      justfile,
+      working_directory,
+    })
+  }
+
   fn justfile(directory: &Path) -> SearchResult<PathBuf> {
     for directory in directory.ancestors() {
       let mut candidates = Vec::new();
@@ -141,7 +161,7 @@ impl Search {
     Err(SearchError::NotFound)
   }
 
-  fn clean(invocation_directory: &Path, path: &Path) -> PathBuf {
+  pub(crate) fn clean(invocation_directory: &Path, path: &Path) -> PathBuf {
     let path = invocation_directory.join(path);
 
     let mut clean = Vec::new();
@@ -341,6 +361,28 @@ mod tests {
     assert_eq!(search.working_directory, sub);
   }
 
+  #[test]
+  fn search_parent_directory() {
+    let tmp = tmptree! {
+      justfile: "",
+      a: {
+        b: {
+          justfile: "",
+        },
+      },
+    };
+
+    let search = Search {
+      justfile:          tmp.path().join("a/b/justfile"),
+      working_directory: tmp.path().join("a/b"),
+    };
+
+    let parent = search.search_parent_directory().unwrap();
+
+    assert_eq!(parent.justfile, tmp.path().join("justfile"));
+    assert_eq!(parent.working_directory, tmp.path());
+  }
+
   #[test]
   fn clean() {
     let cases = &[
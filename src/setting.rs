@@ -2,7 +2,14 @@ use crate::common::*;
 
 #[derive(Debug)]
 pub(crate) enum Setting<'src> {
+  DotenvFilename(StringLiteral<'src>),
+  DotenvLoad(bool),
+  DotenvPath(Vec<StringLiteral<'src>>),
+  Export(bool),
+  Fallback(bool),
+  PositionalArguments(bool),
   Shell(Shell<'src>),
+  WorkingDirectory(StringLiteral<'src>),
 }
 
 #[derive(Debug, PartialEq)]
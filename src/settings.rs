@@ -2,29 +2,67 @@ use crate::common::*;
 
 #[derive(Debug, PartialEq)]
 pub(crate) struct Settings<'src> {
-  pub(crate) shell: Option<setting::Shell<'src>>,
+  pub(crate) dotenv_filename:      Option<String>,
+  pub(crate) dotenv_load:          Option<bool>,
+  pub(crate) dotenv_path:          Vec<PathBuf>,
+  pub(crate) export:               bool,
+  pub(crate) fallback:             bool,
+  pub(crate) positional_arguments: bool,
+  pub(crate) shell:                Option<setting::Shell<'src>>,
+  pub(crate) working_directory:    Option<PathBuf>,
 }
 
 impl<'src> Settings<'src> {
   pub(crate) fn new() -> Settings<'src> {
-    Settings { shell: None }
+    Settings {
+      dotenv_filename:      None,
+      dotenv_load:          None,
+      dotenv_path:          Vec::new(),
+      export:               false,
+      fallback:             false,
+      positional_arguments: false,
+      shell:                None,
+      working_directory:    None,
+    }
+  }
+
+  /// Directory in which to run recipes and backticks, the working directory
+  /// joined with the `working-directory` setting, if present
+  pub(crate) fn working_directory(&self, search: &Search) -> PathBuf {
+    match &self.working_directory {
+      Some(working_directory) => search.working_directory.join(working_directory),
+      None => search.working_directory.clone(),
+    }
   }
 
   pub(crate) fn shell_command(&self, config: &Config) -> Command {
-    if let (Some(shell), false) = (&self.shell, config.shell_present) {
-      let mut cmd = Command::new(shell.command.cooked.as_ref());
+    let (shell, arguments) = self.shell(config);
 
-      for argument in &shell.arguments {
-        cmd.arg(argument.cooked.as_ref());
-      }
+    let mut cmd = Command::new(shell);
 
-      cmd
-    } else {
-      let mut cmd = Command::new(&config.shell);
+    cmd.args(arguments);
 
-      cmd.args(&config.shell_args);
+    cmd
+  }
 
-      cmd
+  /// The shell and shell arguments used to run linewise recipes and
+  /// backticks, from the `shell` setting, unless overridden on the command
+  /// line
+  pub(crate) fn shell<'a>(&'a self, config: &'a Config) -> (&'a str, Vec<&'a str>) {
+    if let (Some(shell), false) = (&self.shell, config.shell_present) {
+      (
+        shell.command.cooked.as_ref(),
+        shell
+          .arguments
+          .iter()
+          .map(|argument| argument.cooked.as_ref())
+          .collect(),
+      )
+    } else {
+      (
+        &config.shell,
+        config.shell_args.iter().map(String::as_str).collect(),
+      )
     }
   }
 }
@@ -0,0 +1,92 @@
+use crate::common::*;
+
+/// The kind of a string literal, determined by its delimiter
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub(crate) enum StringKind {
+  /// `"cooked"`
+  Cooked,
+  /// `"""indented cooked"""`
+  IndentedCooked,
+  /// `'''indented raw'''`
+  IndentedRaw,
+  /// `'raw'`
+  Raw,
+}
+
+impl StringKind {
+  /// The string kind of a string literal token, or a string literal that
+  /// begins `text`
+  pub(crate) fn from_token_start(text: &str) -> Option<StringKind> {
+    if text.starts_with("\"\"\"") {
+      Some(Self::IndentedCooked)
+    } else if text.starts_with("'''") {
+      Some(Self::IndentedRaw)
+    } else if text.starts_with('"') {
+      Some(Self::Cooked)
+    } else if text.starts_with('\'') {
+      Some(Self::Raw)
+    } else {
+      None
+    }
+  }
+
+  /// Process the escape sequences in `text`, if strings of this kind
+  /// process escape sequences. On failure, returns the offset of the invalid
+  /// escape sequence and the character following the backslash.
+  pub(crate) fn cook(self, text: &str) -> Result<String, (usize, char)> {
+    if !self.processes_escape_sequences() {
+      return Ok(text.to_owned());
+    }
+
+    let mut cooked = String::new();
+    let mut escape = false;
+    for (i, c) in text.char_indices() {
+      if escape {
+        match c {
+          'n' => cooked.push('\n'),
+          'r' => cooked.push('\r'),
+          't' => cooked.push('\t'),
+          '\\' => cooked.push('\\'),
+          '"' => cooked.push('"'),
+          other => return Err((i - 1, other)),
+        }
+        escape = false;
+      } else if c == '\\' {
+        escape = true;
+      } else {
+        cooked.push(c);
+      }
+    }
+    Ok(cooked)
+  }
+
+  pub(crate) fn delimiter(self) -> &'static str {
+    match self {
+      Self::Cooked => "\"",
+      Self::IndentedCooked => "\"\"\"",
+      Self::IndentedRaw => "'''",
+      Self::Raw => "'",
+    }
+  }
+
+  pub(crate) fn indented(self) -> bool {
+    matches!(self, Self::IndentedCooked | Self::IndentedRaw)
+  }
+
+  pub(crate) fn processes_escape_sequences(self) -> bool {
+    matches!(self, Self::Cooked | Self::IndentedCooked)
+  }
+
+  /// Whether an unescaped newline in a string of this kind is an error
+  pub(crate) fn forbids_newlines(self) -> bool {
+    self == Self::Cooked
+  }
+
+  pub(crate) fn token_kind(self) -> TokenKind {
+    if self.processes_escape_sequences() {
+      TokenKind::StringCooked
+    } else {
+      TokenKind::StringRaw
+    }
+  }
+}
@@ -25,6 +25,10 @@ pub(crate) enum Subcommand {
   },
   Summary,
   Variables,
+  Watch {
+    overrides: BTreeMap<String, String>,
+    arguments: Vec<String>,
+  },
 }
 
 const FISH_RECIPE_COMPLETIONS: &str = r#"function __fish_just_complete_recipes
@@ -14,20 +14,22 @@
 
 use std::{collections::BTreeMap, fs, io, path::Path};
 
-use crate::compiler::Compiler;
+use crate::{compiler::Compiler, loader::Loader};
 
 mod full {
   pub(crate) use crate::{
-    assignment::Assignment, dependency::Dependency, expression::Expression, fragment::Fragment,
-    justfile::Justfile, line::Line, parameter::Parameter, parameter_kind::ParameterKind,
-    recipe::Recipe, thunk::Thunk,
+    assignment::Assignment, condition::Condition, conditional_operator::ConditionalOperator,
+    dependency::Dependency, expression::Expression, fragment::Fragment, justfile::Justfile,
+    line::Line, parameter::Parameter, parameter_kind::ParameterKind, recipe::Recipe, thunk::Thunk,
   };
 }
 
 pub fn summary(path: &Path) -> Result<Result<Summary, String>, io::Error> {
+  let loader = Loader::new();
+
   let text = fs::read_to_string(path)?;
 
-  match Compiler::compile(&text) {
+  match Compiler::compile(&loader, path, &text) {
     Ok(justfile) => Ok(Ok(Summary::new(justfile))),
     Err(compilation_error) => Ok(Err(compilation_error.to_string())),
   }
@@ -75,9 +77,11 @@ pub struct Recipe {
   pub aliases:      Vec<String>,
   pub dependencies: Vec<Dependency>,
   pub lines:        Vec<Line>,
+  pub locals:       BTreeMap<String, Expression>,
   pub private:      bool,
   pub quiet:        bool,
   pub shebang:      bool,
+  pub subsequents:  Vec<Dependency>,
   pub parameters:   Vec<Parameter>,
 }
 
@@ -87,12 +91,19 @@ impl Recipe {
       private: recipe.private,
       shebang: recipe.shebang,
       quiet: recipe.quiet,
-      dependencies: recipe
-        .dependencies
+      dependencies: recipe.priors().iter().map(Dependency::new).collect(),
+      subsequents: recipe.subsequents().iter().map(Dependency::new).collect(),
+      lines: recipe.body.iter().map(Line::new).collect(),
+      locals: recipe
+        .locals
         .iter()
-        .map(|dependency| Dependency::new(dependency))
+        .map(|local| {
+          (
+            local.name.lexeme().to_owned(),
+            Expression::new(&local.value),
+          )
+        })
         .collect(),
-      lines: recipe.body.iter().map(Line::new).collect(),
       parameters: recipe.parameters.iter().map(Parameter::new).collect(),
       aliases,
     }
@@ -101,6 +112,7 @@ impl Recipe {
 
 #[derive(Eq, PartialEq, Hash, Ord, PartialOrd, Debug, Clone)]
 pub struct Parameter {
+  pub export:  bool,
   pub kind:    ParameterKind,
   pub name:    String,
   pub default: Option<Expression>,
@@ -109,6 +121,7 @@ pub struct Parameter {
 impl Parameter {
   fn new(parameter: &full::Parameter) -> Parameter {
     Parameter {
+      export:  parameter.export,
       kind:    ParameterKind::new(parameter.kind),
       name:    parameter.name.lexeme().to_owned(),
       default: parameter.default.as_ref().map(Expression::new),
@@ -180,6 +193,66 @@ impl Assignment {
   }
 }
 
+#[derive(Eq, PartialEq, Hash, Ord, PartialOrd, Debug, Clone)]
+pub enum Condition {
+  Comparison {
+    lhs:      Box<Expression>,
+    rhs:      Box<Expression>,
+    operator: ConditionalOperator,
+  },
+  And {
+    lhs: Box<Condition>,
+    rhs: Box<Condition>,
+  },
+  Or {
+    lhs: Box<Condition>,
+    rhs: Box<Condition>,
+  },
+  Not {
+    operand: Box<Condition>,
+  },
+}
+
+impl Condition {
+  fn new(condition: &full::Condition) -> Condition {
+    match condition {
+      full::Condition::Comparison { lhs, rhs, operator } => Condition::Comparison {
+        lhs:      Box::new(Expression::new(lhs)),
+        rhs:      Box::new(Expression::new(rhs)),
+        operator: ConditionalOperator::new(*operator),
+      },
+      full::Condition::And { lhs, rhs } => Condition::And {
+        lhs: Box::new(Condition::new(lhs)),
+        rhs: Box::new(Condition::new(rhs)),
+      },
+      full::Condition::Or { lhs, rhs } => Condition::Or {
+        lhs: Box::new(Condition::new(lhs)),
+        rhs: Box::new(Condition::new(rhs)),
+      },
+      full::Condition::Not { operand } => Condition::Not {
+        operand: Box::new(Condition::new(operand)),
+      },
+    }
+  }
+}
+
+#[derive(Eq, PartialEq, Hash, Ord, PartialOrd, Debug, Clone, Copy)]
+pub enum ConditionalOperator {
+  Equality,
+  Inequality,
+  RegexMatch,
+}
+
+impl ConditionalOperator {
+  fn new(operator: full::ConditionalOperator) -> ConditionalOperator {
+    match operator {
+      full::ConditionalOperator::Equality => ConditionalOperator::Equality,
+      full::ConditionalOperator::Inequality => ConditionalOperator::Inequality,
+      full::ConditionalOperator::RegexMatch => ConditionalOperator::RegexMatch,
+    }
+  }
+}
+
 #[derive(Eq, PartialEq, Hash, Ord, PartialOrd, Debug, Clone)]
 pub enum Expression {
   Backtick {
@@ -193,12 +266,17 @@ pub enum Expression {
     lhs: Box<Expression>,
     rhs: Box<Expression>,
   },
+  Join {
+    lhs: Option<Box<Expression>>,
+    rhs: Box<Expression>,
+  },
   Conditional {
-    lhs:       Box<Expression>,
-    rhs:       Box<Expression>,
+    condition: Condition,
     then:      Box<Expression>,
     otherwise: Box<Expression>,
-    inverted:  bool,
+  },
+  FormatString {
+    fragments: Vec<Fragment>,
   },
   String {
     text: String,
@@ -230,23 +308,48 @@ impl Expression {
           name:      name.lexeme().to_owned(),
           arguments: vec![Expression::new(a), Expression::new(b)],
         },
+        full::Thunk::BinaryPlus {
+          name,
+          args: ([a, b], rest),
+          ..
+        } => {
+          let mut arguments = vec![Expression::new(a), Expression::new(b)];
+          for arg in rest {
+            arguments.push(Expression::new(arg));
+          }
+          Expression::Call {
+            name: name.lexeme().to_owned(),
+            arguments,
+          }
+        },
+        full::Thunk::Ternary {
+          name,
+          args: [a, b, c],
+          ..
+        } => Expression::Call {
+          name:      name.lexeme().to_owned(),
+          arguments: vec![Expression::new(a), Expression::new(b), Expression::new(c)],
+        },
       },
       Concatination { lhs, rhs } => Expression::Concatination {
         lhs: Box::new(Expression::new(lhs)),
         rhs: Box::new(Expression::new(rhs)),
       },
+      Join { lhs, rhs } => Expression::Join {
+        lhs: lhs.as_ref().map(|lhs| Box::new(Expression::new(lhs))),
+        rhs: Box::new(Expression::new(rhs)),
+      },
       Conditional {
-        lhs,
-        rhs,
-        inverted,
+        condition,
         then,
         otherwise,
       } => Expression::Conditional {
-        lhs:       Box::new(Expression::new(lhs)),
-        rhs:       Box::new(Expression::new(rhs)),
+        condition: Condition::new(condition),
         then:      Box::new(Expression::new(then)),
         otherwise: Box::new(Expression::new(otherwise)),
-        inverted:  *inverted,
+      },
+      FormatString { fragments, .. } => Expression::FormatString {
+        fragments: fragments.iter().map(Fragment::new).collect(),
       },
       StringLiteral { string_literal } => Expression::String {
         text: string_literal.cooked.to_string(),
@@ -3,7 +3,7 @@ use crate::common::*;
 use pretty_assertions::assert_eq;
 
 pub(crate) fn compile(text: &str) -> Justfile {
-  match Compiler::compile(text) {
+  match Compiler::test_compile(text) {
     Ok(justfile) => justfile,
     Err(error) => panic!("Expected successful compilation but got error:\n {}", error),
   }
@@ -61,12 +61,13 @@ pub(crate) fn analysis_error(
 
   let module = Parser::parse(&tokens).expect("Parsing failed in analysis test...");
 
-  match Analyzer::analyze(module) {
+  match Analyzer::analyze(module, BTreeMap::new()) {
     Ok(_) => panic!("Analysis unexpectedly succeeded"),
     Err(have) => {
       let want = CompilationError {
         token: Token {
           kind: have.token.kind,
+          path: Path::new(""),
           src,
           offset,
           line,
@@ -94,7 +95,7 @@ macro_rules! run_error {
       let search = $crate::testing::search(&config);
 
       if let Subcommand::Run{ overrides, arguments } = &config.subcommand {
-        match $crate::compiler::Compiler::compile(&$crate::testing::unindent($src))
+        match $crate::compiler::Compiler::test_compile(&$crate::testing::unindent($src))
           .expect("Expected successful compilation")
           .run(
             &config,
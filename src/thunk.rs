@@ -20,6 +20,18 @@ pub(crate) enum Thunk<'src> {
     function: fn(&FunctionContext, &str, &str) -> Result<String, String>,
     args:     [Box<Expression<'src>>; 2],
   },
+  BinaryPlus {
+    name:     Name<'src>,
+    #[derivative(Debug = "ignore", PartialEq = "ignore")]
+    function: fn(&FunctionContext, &str, &str, &[String]) -> Result<String, String>,
+    args:     ([Box<Expression<'src>>; 2], Vec<Expression<'src>>),
+  },
+  Ternary {
+    name:     Name<'src>,
+    #[derivative(Debug = "ignore", PartialEq = "ignore")]
+    function: fn(&FunctionContext, &str, &str, &str) -> Result<String, String>,
+    args:     [Box<Expression<'src>>; 3],
+  },
 }
 
 impl<'src> Thunk<'src> {
@@ -47,6 +59,26 @@ impl<'src> Thunk<'src> {
             name,
           })
         },
+        (Function::BinaryPlus(function), 2..=usize::MAX) => {
+          let rest = arguments.drain(2..).collect();
+          let b = Box::new(arguments.pop().unwrap());
+          let a = Box::new(arguments.pop().unwrap());
+          Ok(Thunk::BinaryPlus {
+            function: *function,
+            args: ([a, b], rest),
+            name,
+          })
+        },
+        (Function::Ternary(function), 3) => {
+          let c = Box::new(arguments.pop().unwrap());
+          let b = Box::new(arguments.pop().unwrap());
+          let a = Box::new(arguments.pop().unwrap());
+          Ok(Thunk::Ternary {
+            function: *function,
+            args: [a, b, c],
+            name,
+          })
+        },
         _ => Err(
           name.error(CompilationErrorKind::FunctionArgumentCountMismatch {
             function: name.lexeme(),
@@ -72,6 +104,22 @@ impl Display for Thunk<'_> {
       Binary {
         name, args: [a, b], ..
       } => write!(f, "{}({}, {})", name.lexeme(), a, b),
+      BinaryPlus {
+        name,
+        args: ([a, b], rest),
+        ..
+      } => {
+        write!(f, "{}({}, {}", name.lexeme(), a, b)?;
+        for arg in rest {
+          write!(f, ", {}", arg)?;
+        }
+        write!(f, ")")
+      },
+      Ternary {
+        name,
+        args: [a, b, c],
+        ..
+      } => write!(f, "{}({}, {}, {})", name.lexeme(), a, b, c),
     }
   }
 }
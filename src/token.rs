@@ -6,6 +6,7 @@ pub(crate) struct Token<'src> {
   pub(crate) length: usize,
   pub(crate) line:   usize,
   pub(crate) column: usize,
+  pub(crate) path:   &'src Path,
   pub(crate) src:    &'src str,
   pub(crate) kind:   TokenKind,
 }
@@ -50,6 +51,17 @@ impl<'src> Token<'src> {
           i += c.len_utf8();
         }
         let line_number_width = line_number.to_string().len();
+        if !self.path.as_os_str().is_empty() {
+          writeln!(
+            f,
+            "{0:1$}--> {2}:{3}:{4}",
+            "",
+            line_number_width,
+            self.path.display(),
+            line_number,
+            self.column.ordinal(),
+          )?;
+        }
         writeln!(f, "{0:1$} |", "", line_number_width)?;
         writeln!(f, "{} | {}", line_number, space_line)?;
         write!(f, "{0:1$} |", "", line_number_width)?;
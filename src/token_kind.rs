@@ -2,23 +2,31 @@ use crate::common::*;
 
 #[derive(Debug, PartialEq, Clone, Copy, Ord, PartialOrd, Eq)]
 pub(crate) enum TokenKind {
+  AmpersandAmpersand,
   Asterisk,
   At,
   Backtick,
+  Bang,
   BangEquals,
+  BarBar,
   BraceL,
   BraceR,
   BracketL,
   BracketR,
   Colon,
+  ColonColon,
   ColonEquals,
   Comma,
   Comment,
   Dedent,
+  Dollar,
   Eof,
   Eol,
   Equals,
   EqualsEquals,
+  EqualsTilde,
+  FormatStringEnd,
+  FormatStringStart,
   Identifier,
   Indent,
   InterpolationEnd,
@@ -26,6 +34,7 @@ pub(crate) enum TokenKind {
   ParenL,
   ParenR,
   Plus,
+  Slash,
   StringCooked,
   StringRaw,
   Text,
@@ -37,23 +46,31 @@ impl Display for TokenKind {
   fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
     use TokenKind::*;
     write!(f, "{}", match *self {
+      AmpersandAmpersand => "'&&'",
       Asterisk => "'*'",
       At => "'@'",
       Backtick => "backtick",
+      Bang => "'!'",
       BangEquals => "'!='",
+      BarBar => "'||'",
       BraceL => "'{'",
       BraceR => "'}'",
       BracketL => "'['",
       BracketR => "']'",
       Colon => "':'",
+      ColonColon => "'::'",
       ColonEquals => "':='",
       Comma => "','",
       Comment => "comment",
       Dedent => "dedent",
+      Dollar => "'$'",
       Eof => "end of file",
       Eol => "end of line",
       Equals => "'='",
       EqualsEquals => "'=='",
+      EqualsTilde => "'=~'",
+      FormatStringEnd => "format string end",
+      FormatStringStart => "format string start",
       Identifier => "identifier",
       Indent => "indent",
       InterpolationEnd => "'}}'",
@@ -61,6 +78,7 @@ impl Display for TokenKind {
       ParenL => "'('",
       ParenR => "')'",
       Plus => "'+'",
+      Slash => "'/'",
       StringCooked => "cooked string",
       StringRaw => "raw string",
       Text => "command text",
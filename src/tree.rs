@@ -36,6 +36,12 @@ macro_rules! tree {
     $crate::tree::Tree::atom("+")
   };
 
+  {
+    /
+  } => {
+    $crate::tree::Tree::atom("/")
+  };
+
   {
     *
   } => {
@@ -53,6 +59,24 @@ macro_rules! tree {
   } => {
     $crate::tree::Tree::atom("!=")
   };
+
+  {
+    !
+  } => {
+    $crate::tree::Tree::atom("!")
+  };
+
+  {
+    &&
+  } => {
+    $crate::tree::Tree::atom("&&")
+  };
+
+  {
+    ||
+  } => {
+    $crate::tree::Tree::atom("||")
+  };
 }
 
 /// A `Tree` is either…
@@ -0,0 +1,82 @@
+/// Remove the longest common leading indentation from the lines of `text`.
+/// Lines that consist entirely of whitespace do not contribute to the common
+/// indentation, and are emptied.
+pub(crate) fn unindent(text: &str) -> String {
+  let lines = text.split_inclusive('\n').collect::<Vec<&str>>();
+
+  let common_indentation = lines
+    .iter()
+    .filter(|line| !blank(line))
+    .map(|line| indentation(line))
+    .fold(None, |common: Option<&str>, indentation| {
+      Some(match common {
+        Some(common) => common_prefix(common, indentation),
+        None => indentation,
+      })
+    })
+    .unwrap_or("");
+
+  let mut unindented = String::new();
+
+  for line in lines {
+    if blank(line) {
+      if line.ends_with("\r\n") {
+        unindented.push_str("\r\n");
+      } else if line.ends_with('\n') {
+        unindented.push('\n');
+      }
+    } else {
+      unindented.push_str(&line[common_indentation.len()..]);
+    }
+  }
+
+  unindented
+}
+
+fn blank(line: &str) -> bool {
+  line.chars().all(char::is_whitespace)
+}
+
+fn indentation(line: &str) -> &str {
+  let end = line
+    .char_indices()
+    .find(|(_, c)| *c != ' ' && *c != '\t')
+    .map_or(line.len(), |(i, _)| i);
+
+  &line[..end]
+}
+
+fn common_prefix<'a>(a: &'a str, b: &str) -> &'a str {
+  let end = a
+    .char_indices()
+    .zip(b.chars())
+    .find(|((_, a), b)| a != b)
+    .map_or(a.len().min(b.len()), |((i, _), _)| i);
+
+  &a[..end]
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn unindents_common_indentation() {
+    assert_eq!(unindent("  a\n    b\n  c\n"), "a\n  b\nc\n");
+  }
+
+  #[test]
+  fn ignores_blank_lines() {
+    assert_eq!(unindent("  a\n\n \n  b\n  "), "a\n\n\nb\n");
+  }
+
+  #[test]
+  fn mixed_indentation() {
+    assert_eq!(unindent("\t a\n\t\tb\n"), " a\n\tb\n");
+  }
+
+  #[test]
+  fn no_indentation() {
+    assert_eq!(unindent("a\n  b"), "a\n  b");
+  }
+}
@@ -5,7 +5,7 @@ pub(crate) type UnresolvedRecipe<'src> = Recipe<'src, UnresolvedDependency<'src>
 impl<'src> UnresolvedRecipe<'src> {
   pub(crate) fn resolve(
     self,
-    resolved: Vec<Rc<Recipe<'src>>>,
+    resolved: Vec<Arc<Recipe<'src>>>,
   ) -> CompilationResult<'src, Recipe<'src>> {
     assert_eq!(self.dependencies.len(), resolved.len());
     for (unresolved, resolved) in self.dependencies.iter().zip(&resolved) {
@@ -30,16 +30,21 @@ impl<'src> UnresolvedRecipe<'src> {
       .into_iter()
       .zip(resolved)
       .map(|(unresolved, resolved)| Dependency {
+        modules:   unresolved.modules,
         recipe:    resolved,
         arguments: unresolved.arguments,
       })
       .collect();
 
     Ok(Recipe {
+      attributes: self.attributes,
       doc: self.doc,
       body: self.body,
+      body_line: self.body_line,
+      locals: self.locals,
       name: self.name,
       parameters: self.parameters,
+      priors: self.priors,
       private: self.private,
       quiet: self.quiet,
       shebang: self.shebang,
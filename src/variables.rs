@@ -8,40 +8,80 @@ impl<'expression, 'src> Variables<'expression, 'src> {
   pub(crate) fn new(root: &'expression Expression<'src>) -> Variables<'expression, 'src> {
     Variables { stack: vec![root] }
   }
+
+  fn push_condition(&mut self, condition: &'expression Condition<'src>) {
+    match condition {
+      Condition::Comparison { lhs, rhs, .. } => {
+        self.stack.push(lhs);
+        self.stack.push(rhs);
+      },
+      Condition::And { lhs, rhs } | Condition::Or { lhs, rhs } => {
+        self.push_condition(lhs);
+        self.push_condition(rhs);
+      },
+      Condition::Not { operand } => self.push_condition(operand),
+    }
+  }
 }
 
 impl<'expression, 'src> Iterator for Variables<'expression, 'src> {
   type Item = Token<'src>;
 
   fn next(&mut self) -> Option<Token<'src>> {
-    match self.stack.pop() {
-      None
-      | Some(Expression::StringLiteral { .. })
-      | Some(Expression::Backtick { .. })
-      | Some(Expression::Call { .. }) => None,
-      Some(Expression::Conditional {
-        lhs,
-        rhs,
-        then,
-        otherwise,
-        ..
-      }) => {
-        self.stack.push(lhs);
-        self.stack.push(rhs);
-        self.stack.push(then);
-        self.stack.push(otherwise);
-        self.next()
-      },
-      Some(Expression::Variable { name, .. }) => Some(name.token()),
-      Some(Expression::Concatination { lhs, rhs }) => {
-        self.stack.push(lhs);
-        self.stack.push(rhs);
-        self.next()
-      },
-      Some(Expression::Group { contents }) => {
-        self.stack.push(contents);
-        self.next()
-      },
+    loop {
+      match self.stack.pop()? {
+        Expression::StringLiteral { .. } | Expression::Backtick { .. } => {},
+        Expression::Call { thunk } => match thunk {
+          Thunk::Nullary { .. } => {},
+          Thunk::Unary { arg, .. } => self.stack.push(arg),
+          Thunk::Binary { args, .. } =>
+            for arg in args {
+              self.stack.push(arg);
+            },
+          Thunk::BinaryPlus {
+            args: (binary, rest),
+            ..
+          } => {
+            for arg in binary {
+              self.stack.push(arg);
+            }
+            for arg in rest {
+              self.stack.push(arg);
+            }
+          },
+          Thunk::Ternary { args, .. } =>
+            for arg in args {
+              self.stack.push(arg);
+            },
+        },
+        Expression::Conditional {
+          condition,
+          then,
+          otherwise,
+        } => {
+          self.push_condition(condition);
+          self.stack.push(then);
+          self.stack.push(otherwise);
+        },
+        Expression::Variable { name, .. } => return Some(name.token()),
+        Expression::Concatination { lhs, rhs } => {
+          self.stack.push(lhs);
+          self.stack.push(rhs);
+        },
+        Expression::Join { lhs, rhs } => {
+          if let Some(lhs) = lhs {
+            self.stack.push(lhs);
+          }
+          self.stack.push(rhs);
+        },
+        Expression::FormatString { fragments, .. } =>
+          for fragment in fragments {
+            if let Fragment::Interpolation { expression } = fragment {
+              self.stack.push(expression);
+            }
+          },
+        Expression::Group { contents } => self.stack.push(contents),
+      }
     }
   }
 }
@@ -5,12 +5,14 @@ use Warning::*;
 #[derive(Debug, PartialEq)]
 pub(crate) enum Warning<'src> {
   DeprecatedEquals { equals: Token<'src> },
+  UnusedRecipeLocal { name: Token<'src> },
 }
 
 impl<'src> Warning<'src> {
   fn context(&self) -> Option<&Token<'src>> {
     match self {
       DeprecatedEquals { equals } => Some(equals),
+      UnusedRecipeLocal { name } => Some(name),
     }
   }
 }
@@ -33,6 +35,13 @@ impl Display for Warning<'_> {
           "Please see this issue for more details: https://github.com/casey/just/issues/379"
         )?;
       },
+      UnusedRecipeLocal { name } => {
+        writeln!(f, "Recipe-local variable `{}` is never used", name.lexeme())?;
+        write!(
+          f,
+          "`let` lines at the start of a recipe body are recipe-local assignments, not commands"
+        )?;
+      },
     }
 
     write!(f, "{}", message.suffix())?;
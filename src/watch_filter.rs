@@ -0,0 +1,81 @@
+use crate::common::*;
+
+use ignore::gitignore::Gitignore;
+
+/// Which changed paths cause `--watch` to run recipes again, namely the
+/// justfile and the paths in the working directory which aren't hidden or
+/// ignored by `.gitignore` or `.ignore`. If `patterns` isn't empty, only paths
+/// in the working directory whose relative paths match one of them count.
+pub(crate) struct WatchFilter<'path> {
+  justfile:          &'path Path,
+  patterns:          Vec<glob::Pattern>,
+  working_directory: &'path Path,
+}
+
+impl<'path> WatchFilter<'path> {
+  pub(crate) fn new(
+    justfile: &'path Path,
+    working_directory: &'path Path,
+    patterns: Vec<glob::Pattern>,
+  ) -> Self {
+    Self {
+      justfile,
+      patterns,
+      working_directory,
+    }
+  }
+
+  pub(crate) fn matches(&self, path: &Path) -> bool {
+    if path == self.justfile {
+      return true;
+    }
+
+    let relative = match path.strip_prefix(self.working_directory) {
+      Ok(relative) => relative,
+      Err(_) => return false,
+    };
+
+    if relative
+      .components()
+      .any(|component| component.as_os_str().to_string_lossy().starts_with('.'))
+    {
+      return false;
+    }
+
+    if !self.patterns.is_empty()
+      && !self
+        .patterns
+        .iter()
+        .any(|pattern| pattern.matches_path(relative))
+    {
+      return false;
+    }
+
+    !Self::ignored(path)
+  }
+
+  /// Whether `path` is ignored by the `.ignore` or `.gitignore` files in the
+  /// directories above it. Files in deeper directories take precedence, and
+  /// `.ignore` takes precedence over `.gitignore`.
+  fn ignored(path: &Path) -> bool {
+    let is_dir = path.is_dir();
+
+    for directory in path.ancestors().skip(1) {
+      for name in &[".ignore", ".gitignore"] {
+        let (gitignore, _error) = Gitignore::new(directory.join(name));
+
+        let matched = gitignore.matched_path_or_any_parents(path, is_dir);
+
+        if matched.is_ignore() {
+          return true;
+        }
+
+        if matched.is_whitelist() {
+          return false;
+        }
+      }
+    }
+
+    false
+  }
+}
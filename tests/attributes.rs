@@ -0,0 +1,368 @@
+use crate::common::*;
+
+test! {
+  name:     private_attribute,
+  justfile: "
+    [private]
+    foo:
+
+    bar:
+  ",
+  args:     ("--list"),
+  stdout:   "
+    Available recipes:
+        bar
+  ",
+}
+
+test! {
+  name:     private_attribute_summary,
+  justfile: "
+    [private]
+    foo:
+
+    bar:
+  ",
+  args:     ("--summary"),
+  stdout:   "bar\n",
+}
+
+test! {
+  name:     private_attribute_still_runnable,
+  justfile: "
+    [private]
+    foo:
+      @echo foo
+  ",
+  args:     ("foo"),
+  stdout:   "foo\n",
+}
+
+test! {
+  name:     no_exit_message,
+  justfile: "
+    [no-exit-message]
+    foo:
+      @exit 100
+  ",
+  status:   100,
+}
+
+test! {
+  name:     no_exit_message_shebang,
+  justfile: "
+    [no-exit-message]
+    foo:
+      #!/usr/bin/env bash
+      exit 100
+  ",
+  status:   100,
+}
+
+test! {
+  name:     exit_message_without_attribute,
+  justfile: "
+    foo:
+      @exit 100
+  ",
+  stderr:   "error: Recipe `foo` failed on line 2 with exit code 100\n",
+  status:   100,
+}
+
+test! {
+  name:     show_attributes,
+  justfile: "
+    [no-cd, private]
+    foo:
+      echo foo
+  ",
+  args:     ("--show", "foo"),
+  stdout:   "
+    [no-cd]
+    [private]
+    foo:
+        echo foo
+  ",
+}
+
+test! {
+  name:     unknown_attribute,
+  justfile: "
+    [foo]
+    bar:
+  ",
+  stderr:   "
+    error: Unknown attribute `foo`
+      |
+    1 | [foo]
+      |  ^^^
+  ",
+  status:   EXIT_FAILURE,
+}
+
+#[test]
+fn no_cd() {
+  let tmp = tmptree! {
+    justfile: "
+[no-cd]
+linewise:
+  cat data
+
+[no-cd]
+shebang:
+  #!/bin/sh
+  cat data
+",
+    data: "root",
+    subdir: {
+      data: "subdir",
+    },
+  };
+
+  let output = Command::new(executable_path("just"))
+    .current_dir(tmp.path().join("subdir"))
+    .args(&["shebang", "linewise"])
+    .output()
+    .expect("just invocation failed");
+
+  assert_eq!(str::from_utf8(&output.stdout).unwrap(), "subdirsubdir");
+  assert!(output.status.success());
+}
+
+#[test]
+fn cd_without_attribute() {
+  let tmp = tmptree! {
+    justfile: "
+foo:
+  cat data
+",
+    data: "root",
+    subdir: {
+      data: "subdir",
+    },
+  };
+
+  let output = Command::new(executable_path("just"))
+    .current_dir(tmp.path().join("subdir"))
+    .output()
+    .expect("just invocation failed");
+
+  assert_eq!(str::from_utf8(&output.stdout).unwrap(), "root");
+  assert!(output.status.success());
+}
+
+#[test]
+fn working_directory() {
+  let tmp = tmptree! {
+    justfile: "
+[working-directory('subdir')]
+linewise param=`cat data`:
+  let local := `cat data`
+  @echo {{param}} {{local}} `cat data`
+
+[working-directory('subdir')]
+shebang:
+  #!/bin/sh
+  cat data
+",
+    data: "root",
+    subdir: {
+      data: "subdir",
+    },
+  };
+
+  let output = Command::new(executable_path("just"))
+    .current_dir(tmp.path())
+    .args(&["shebang", "linewise"])
+    .output()
+    .expect("just invocation failed");
+
+  assert_eq!(
+    str::from_utf8(&output.stdout).unwrap(),
+    "subdirsubdir subdir subdir\n"
+  );
+  assert!(output.status.success());
+}
+
+test! {
+  name:     show_working_directory_attribute,
+  justfile: "
+    [working-directory('foo')]
+    bar:
+      echo bar
+  ",
+  args:     ("--show", "bar"),
+  stdout:   "
+    [working-directory('foo')]
+    bar:
+        echo bar
+  ",
+}
+
+test! {
+  name:     no_cd_and_working_directory,
+  justfile: "
+    [no-cd]
+    [working-directory('foo')]
+    bar:
+  ",
+  stderr:   "
+    error: Recipe attribute `working-directory` may not be used with attribute `no-cd`
+      |
+    2 | [working-directory('foo')]
+      |  ^^^^^^^^^^^^^^^^^
+  ",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     show_inputs_and_outputs_attributes,
+  justfile: "
+    [inputs('src/*.c', 'include/*.h')]
+    [outputs('main')]
+    build:
+      cc src/*.c -o main
+  ",
+  args:     ("--show", "build"),
+  stdout:   "
+    [inputs('src/*.c', 'include/*.h')]
+    [outputs('main')]
+    build:
+        cc src/*.c -o main
+  ",
+}
+
+test! {
+  name:     inputs_invalid_glob,
+  justfile: "
+    [inputs('src/[.c')]
+    build:
+  ",
+  stderr:   "
+    error: Invalid glob pattern: Pattern syntax error near position 4: invalid range pattern
+      |
+    1 | [inputs('src/[.c')]
+      |  ^^^^^^
+  ",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     show_watch_attribute,
+  justfile: "
+    [watch('src/*.rs', 'Cargo.toml')]
+    test:
+      cargo test
+  ",
+  args:     ("--show", "test"),
+  stdout:   "
+    [watch('src/*.rs', 'Cargo.toml')]
+    test:
+        cargo test
+  ",
+}
+
+test! {
+  name:     watch_invalid_glob,
+  justfile: "
+    [watch('src/[.rs')]
+    test:
+  ",
+  stderr:   "
+    error: Invalid glob pattern: Pattern syntax error near position 4: invalid range pattern
+      |
+    1 | [watch('src/[.rs')]
+      |  ^^^^^
+  ",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     watch_without_argument,
+  justfile: "
+    [watch]
+    test:
+  ",
+  stderr:   "
+    error: Attribute `watch` got 0 arguments but takes at least 1 argument
+      |
+    1 | [watch]
+      |  ^^^^^
+  ",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     show_timeout_attribute,
+  justfile: "
+    [timeout('5m')]
+    test:
+      cargo test
+  ",
+  args:     ("--show", "test"),
+  stdout:   "
+    [timeout('5m')]
+    test:
+        cargo test
+  ",
+}
+
+test! {
+  name:     timeout_invalid,
+  justfile: "
+    [timeout('5')]
+    test:
+  ",
+  stderr:   "
+    error: Invalid timeout: time unit needed, for example 5sec or 5ms
+      |
+    1 | [timeout('5')]
+      |  ^^^^^^^
+  ",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     show_retry_attribute,
+  justfile: "
+    [retry('3', '1s')]
+    fetch:
+      curl example.com
+  ",
+  args:     ("--show", "fetch"),
+  stdout:   "
+    [retry('3', '1s')]
+    fetch:
+        curl example.com
+  ",
+}
+
+test! {
+  name:     retry_invalid_count,
+  justfile: "
+    [retry('many')]
+    fetch:
+  ",
+  stderr:   "
+    error: Invalid retry: `many` is not a valid retry count
+      |
+    1 | [retry('many')]
+      |  ^^^^^
+  ",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     retry_invalid_backoff,
+  justfile: "
+    [retry('3', '1')]
+    fetch:
+  ",
+  stderr:   "
+    error: Invalid retry: invalid backoff: time unit needed, for example 1sec or 1ms
+      |
+    1 | [retry('3', '1')]
+      |  ^^^^^
+  ",
+  status:   EXIT_FAILURE,
+}
@@ -0,0 +1,356 @@
+use crate::common::*;
+
+#[test]
+fn hit() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [cache]
+      build:
+        @echo build
+    ",
+  );
+
+  assert_eq!(run_success(tmp.path(), &[]).0, "build\n");
+  assert_eq!(run_success(tmp.path(), &[]).0, "");
+  assert!(tmp.path().join(".just/cache/build").is_file());
+}
+
+#[test]
+fn module_recipes_are_cached_separately() {
+  let tmp = tmptree! {
+    justfile: "
+mod foo
+
+[cache]
+build:
+  @echo root
+
+[cache]
+foo:
+  @echo recipe
+",
+    "foo.just": "
+[cache]
+build:
+  @echo module
+",
+  };
+
+  assert_eq!(
+    run_success(tmp.path(), &["build", "foo::build", "foo"]).0,
+    "root\nmodule\nrecipe\n"
+  );
+  assert_eq!(
+    run_success(tmp.path(), &["build", "foo::build", "foo"]).0,
+    ""
+  );
+  assert!(tmp.path().join(".just/cache/build").is_file());
+  assert!(tmp.path().join(".just/cache/foo.build").is_file());
+  assert!(tmp.path().join(".just/cache/foo").is_file());
+
+  fs::write(
+    tmp.path().join("foo.just"),
+    "[cache]\nbuild:\n  @echo changed\n",
+  )
+  .unwrap();
+
+  assert_eq!(
+    run_success(tmp.path(), &["build", "foo::build"]).0,
+    "changed\n"
+  );
+}
+
+#[test]
+fn uncached_recipes_always_run() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      build:
+        @echo build
+    ",
+  );
+
+  assert_eq!(run_success(tmp.path(), &[]).0, "build\n");
+  assert_eq!(run_success(tmp.path(), &[]).0, "build\n");
+  assert!(!tmp.path().join(".just").exists());
+}
+
+#[test]
+fn body_changed() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [cache]
+      build:
+        @echo foo
+    ",
+  );
+
+  assert_eq!(run_success(tmp.path(), &[]).0, "foo\n");
+
+  justfile(
+    tmp.path(),
+    "
+      [cache]
+      build:
+        @echo bar
+    ",
+  );
+
+  assert_eq!(run_success(tmp.path(), &[]).0, "bar\n");
+  assert_eq!(run_success(tmp.path(), &[]).0, "");
+}
+
+#[test]
+fn evaluated_body_changed() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      version := 'a'
+
+      [cache]
+      build:
+        @echo {{version}}
+    ",
+  );
+
+  assert_eq!(run_success(tmp.path(), &[]).0, "a\n");
+  assert_eq!(run_success(tmp.path(), &[]).0, "");
+  assert_eq!(run_success(tmp.path(), &["--set", "version", "b"]).0, "b\n");
+}
+
+#[test]
+fn parameters_changed() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      set positional-arguments
+
+      [cache]
+      build target:
+        @echo $1
+    ",
+  );
+
+  assert_eq!(run_success(tmp.path(), &["build", "a"]).0, "a\n");
+  assert_eq!(run_success(tmp.path(), &["build", "a"]).0, "");
+  assert_eq!(run_success(tmp.path(), &["build", "b"]).0, "b\n");
+}
+
+#[test]
+fn exported_environment_changed() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      export FOO := 'a'
+
+      [cache]
+      build:
+        @echo $FOO
+    ",
+  );
+
+  assert_eq!(run_success(tmp.path(), &[]).0, "a\n");
+  assert_eq!(run_success(tmp.path(), &[]).0, "");
+  assert_eq!(run_success(tmp.path(), &["FOO=b"]).0, "b\n");
+}
+
+#[test]
+fn shell_changed() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [cache]
+      build:
+        @echo build
+    ",
+  );
+
+  assert_eq!(run_success(tmp.path(), &[]).0, "build\n");
+  assert_eq!(run_success(tmp.path(), &[]).0, "");
+  assert_eq!(run_success(tmp.path(), &["--shell", "bash"]).0, "build\n");
+  assert_eq!(
+    run_success(tmp.path(), &["--shell-arg", "-ceu"]).0,
+    "build\n"
+  );
+}
+
+#[test]
+fn working_directory_changed() {
+  let tmp = tempdir();
+
+  fs::create_dir(tmp.path().join("a")).unwrap();
+  fs::create_dir(tmp.path().join("b")).unwrap();
+
+  justfile(
+    tmp.path(),
+    "
+      [cache]
+      [working-directory('a')]
+      build:
+        @echo build
+    ",
+  );
+
+  assert_eq!(run_success(tmp.path(), &[]).0, "build\n");
+  assert_eq!(run_success(tmp.path(), &[]).0, "");
+
+  justfile(
+    tmp.path(),
+    "
+      [cache]
+      [working-directory('b')]
+      build:
+        @echo build
+    ",
+  );
+
+  assert_eq!(run_success(tmp.path(), &[]).0, "build\n");
+  assert_eq!(run_success(tmp.path(), &[]).0, "");
+}
+
+#[test]
+fn failures_are_not_cached() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [cache]
+      build:
+        @echo build
+        @exit 1
+    ",
+  );
+
+  for _ in 0..2 {
+    let output = Command::new(executable_path("just"))
+      .current_dir(tmp.path())
+      .output()
+      .unwrap();
+
+    assert!(!output.status.success());
+    assert_eq!(str::from_utf8(&output.stdout).unwrap(), "build\n");
+  }
+}
+
+#[test]
+fn no_cache() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [cache]
+      build:
+        @echo build
+    ",
+  );
+
+  assert_eq!(run_success(tmp.path(), &[]).0, "build\n");
+  assert_eq!(run_success(tmp.path(), &["--no-cache"]).0, "build\n");
+  assert_eq!(run_success(tmp.path(), &[]).0, "");
+}
+
+#[test]
+fn shebang() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [cache]
+      build:
+        #!/usr/bin/env sh
+        echo build
+    ",
+  );
+
+  assert_eq!(run_success(tmp.path(), &[]).0, "build\n");
+  assert_eq!(run_success(tmp.path(), &[]).0, "");
+}
+
+#[test]
+fn continuation_lines() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [cache]
+      build:
+        @echo \\
+          build
+    ",
+  );
+
+  assert_eq!(run_success(tmp.path(), &[]).0, "build\n");
+  assert_eq!(run_success(tmp.path(), &[]).0, "");
+}
+
+#[test]
+fn verbose() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [cache]
+      build:
+        @echo build
+    ",
+  );
+
+  run_success(tmp.path(), &[]);
+
+  let (stdout, stderr) = run_success(tmp.path(), &["--verbose"]);
+
+  assert_eq!(stdout, "");
+  assert!(
+    stderr.contains("===> Recipe `build` is cached\n"),
+    "unexpected stderr: {}",
+    stderr
+  );
+}
+
+#[test]
+fn write_error() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [cache]
+      build:
+        @echo build
+    ",
+  );
+
+  fs::write(tmp.path().join(".just"), "").unwrap();
+
+  let output = Command::new(executable_path("just"))
+    .current_dir(tmp.path())
+    .output()
+    .unwrap();
+
+  assert!(!output.status.success());
+  assert_eq!(str::from_utf8(&output.stdout).unwrap(), "build\n");
+  assert!(str::from_utf8(&output.stderr)
+    .unwrap()
+    .starts_with("error: Failed to write cache file `"));
+}
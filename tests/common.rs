@@ -12,3 +12,36 @@ pub(crate) use executable_path::executable_path;
 pub(crate) use libc::{EXIT_FAILURE, EXIT_SUCCESS};
 pub(crate) use test_utilities::{assert_stdout, tempdir, tmptree, unindent};
 pub(crate) use which::which;
+
+/// Run `just` with `args` in `path`, returning its exit code, stdout, and
+/// stderr
+pub(crate) fn run(path: &Path, args: &[&str]) -> (Option<i32>, String, String) {
+  let output = Command::new(executable_path("just"))
+    .current_dir(path)
+    .args(args)
+    .output()
+    .expect("just invocation failed");
+
+  (
+    output.status.code(),
+    String::from_utf8(output.stdout).unwrap(),
+    String::from_utf8(output.stderr).unwrap(),
+  )
+}
+
+/// Run `just` with `args` in `path`, panicking if it fails, and returning its
+/// stdout and stderr
+pub(crate) fn run_success(path: &Path, args: &[&str]) -> (String, String) {
+  let (status, stdout, stderr) = run(path, args);
+
+  if status != Some(EXIT_SUCCESS) {
+    panic!("just invocation failed: {}", stderr);
+  }
+
+  (stdout, stderr)
+}
+
+/// Write `justfile`, unindented, to `path/justfile`
+pub(crate) fn justfile(path: &Path, justfile: &str) {
+  fs::write(path.join("justfile"), unindent(justfile)).unwrap();
+}
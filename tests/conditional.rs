@@ -132,7 +132,7 @@ test! {
   ",
   stdout: "",
   stderr: "
-    error: Expected '!=', '==', or '+', but found identifier
+    error: Expected '!=', '==', '=~', '+', or '/', but found identifier
       |
     1 | a := if '' a '' { '' } else { b }
       |            ^
@@ -156,3 +156,191 @@ test! {
         echo {{{{a}}}}
   ", " ").as_str(),
 }
+
+test! {
+  name: and,
+  justfile: "
+    foo:
+      echo {{ if 'a' == 'a' && 'b' == 'b' { 'yes' } else { 'no' } }} {{ if 'a' == 'a' && 'b' == 'c' { 'yes' } else { 'no' } }}
+  ",
+  stdout: "yes no\n",
+  stderr: "echo yes no\n",
+}
+
+test! {
+  name: or,
+  justfile: "
+    foo:
+      echo {{ if 'a' == 'b' || 'b' == 'b' { 'yes' } else { 'no' } }} {{ if 'a' == 'b' || 'b' == 'c' { 'yes' } else { 'no' } }}
+  ",
+  stdout: "yes no\n",
+  stderr: "echo yes no\n",
+}
+
+test! {
+  name: and_binds_tighter_than_or,
+  justfile: "
+    foo:
+      echo {{ if 'a' == 'a' || 'a' == 'b' && 'a' == 'c' { 'yes' } else { 'no' } }}
+  ",
+  stdout: "yes\n",
+  stderr: "echo yes\n",
+}
+
+test! {
+  name: not,
+  justfile: "
+    foo:
+      echo {{ if !('a' == 'a') { 'yes' } else { 'no' } }} {{ if !'a' == 'b' { 'yes' } else { 'no' } }}
+  ",
+  stdout: "no yes\n",
+  stderr: "echo no yes\n",
+}
+
+test! {
+  name: parenthesized_condition,
+  justfile: "
+    foo:
+      echo {{ if ('a' == 'b' || 'a' == 'a') && 'b' == 'b' { 'yes' } else { 'no' } }}
+  ",
+  stdout: "yes\n",
+  stderr: "echo yes\n",
+}
+
+test! {
+  name: parenthesized_expression,
+  justfile: "
+    foo:
+      echo {{ if ('a') + 'b' == 'ab' { 'yes' } else { 'no' } }}
+  ",
+  stdout: "yes\n",
+  stderr: "echo yes\n",
+}
+
+test! {
+  name: and_short_circuits,
+  justfile: "
+    foo:
+      echo {{ if 'a' == 'b' && `exit 1` == '' { 'yes' } else { 'no' } }}
+  ",
+  stdout: "no\n",
+  stderr: "echo no\n",
+}
+
+test! {
+  name: or_short_circuits,
+  justfile: "
+    foo:
+      echo {{ if 'a' == 'a' || `exit 1` == '' { 'yes' } else { 'no' } }}
+  ",
+  stdout: "yes\n",
+  stderr: "echo yes\n",
+}
+
+test! {
+  name: regex_match,
+  justfile: "
+    foo:
+      echo {{ if 'v1.2.3' =~ '^v[0-9]+' { 'yes' } else { 'no' } }} {{ if '1.2.3' =~ '^v' { 'yes' } else { 'no' } }}
+  ",
+  stdout: "yes no\n",
+  stderr: "echo yes no\n",
+}
+
+test! {
+  name: regex_match_dynamic,
+  justfile: "
+    pattern := '^a'
+
+    foo:
+      echo {{ if 'abc' =~ pattern + 'b' { 'yes' } else { 'no' } }}
+  ",
+  stdout: "yes\n",
+  stderr: "echo yes\n",
+}
+
+test! {
+  name: invalid_regex,
+  justfile: "
+    a := if 'a' =~ '(' { 'yes' } else { 'no' }
+  ",
+  stderr: "
+    error: Invalid regular expression: regex parse error:
+        (
+        ^
+    error: unclosed group
+      |
+    1 | a := if 'a' =~ '(' { 'yes' } else { 'no' }
+      |                ^^^
+  ",
+  status: EXIT_FAILURE,
+}
+
+test! {
+  name: invalid_dynamic_regex,
+  justfile: "
+    pattern := '('
+
+    foo:
+      echo {{ if 'a' =~ pattern { 'yes' } else { 'no' } }}
+  ",
+  stderr: "
+    error: Failed to compile regular expression: regex parse error:
+        (
+        ^
+    error: unclosed group
+  ",
+  status: EXIT_FAILURE,
+}
+
+test! {
+  name: dump_boolean_operators,
+  justfile: "
+    a := if !('b' == 'c') && ('d' =~ 'e' || 'f' != 'g') { 'h' } else { 'i' }
+  ",
+  args: ("--dump"),
+  stdout: "a := if !('b' == 'c') && ('d' =~ 'e' || 'f' != 'g') { 'h' } else { 'i' } \n",
+}
+
+test! {
+  name: else_if,
+  justfile: "
+    foo:
+      echo {{ if 'a' == 'b' { 'x' } else if 'a' == 'a' { 'y' } else { 'z' } }}
+  ",
+  stdout: "y\n",
+  stderr: "echo y\n",
+}
+
+test! {
+  name: else_if_otherwise,
+  justfile: "
+    foo:
+      echo {{ if 'a' == 'b' { 'x' } else if 'a' == 'c' { 'y' } else if 'a' == 'd' { `exit 1` } else { 'z' } }}
+  ",
+  stdout: "z\n",
+  stderr: "echo z\n",
+}
+
+test! {
+  name: dump_else_if,
+  justfile: "
+    a := if 'a' == 'b' { 'x' } else if 'a' == 'c' { 'y' } else { 'z' }
+  ",
+  args: ("--dump"),
+  stdout: "a := if 'a' == 'b' { 'x' } else if 'a' == 'c' { 'y' } else { 'z' } \n",
+}
+
+test! {
+  name: else_missing_brace,
+  justfile: "
+    a := if 'a' == 'b' { 'x' } else 'y'
+  ",
+  stderr: "
+    error: Expected '{', but found raw string
+      |
+    1 | a := if 'a' == 'b' { 'x' } else 'y'
+      |                                 ^^^
+  ",
+  status: EXIT_FAILURE,
+}
@@ -1,8 +1,33 @@
 use executable_path::executable_path;
-use std::{process, str};
+use std::{
+  path::Path,
+  process::{self, Output},
+  str,
+};
 
 use test_utilities::tmptree;
 
+const JUSTFILE_ECHO: &str = "default:\n\t@echo KEY=${KEY:-unset}";
+
+fn run(path: &Path, args: &[&str]) -> Output {
+  process::Command::new(executable_path("just"))
+    .current_dir(path)
+    .args(args)
+    .output()
+    .expect("just invocation failed")
+}
+
+fn stdout(path: &Path, args: &[&str]) -> String {
+  let output = run(path, args);
+
+  if !output.status.success() {
+    eprintln!("{}", String::from_utf8_lossy(&output.stderr));
+    panic!();
+  }
+
+  String::from_utf8(output.stdout).unwrap()
+}
+
 #[test]
 fn dotenv() {
   let tmp = tmptree! {
@@ -26,3 +51,194 @@ fn dotenv() {
   let stdout = str::from_utf8(&output.stdout).unwrap();
   assert_eq!(stdout, "KEY=SUB\n");
 }
+
+#[test]
+fn no_dotenv_flag() {
+  let tmp = tmptree! {
+    ".env": "KEY=DOTENV",
+    justfile: JUSTFILE_ECHO,
+  };
+
+  assert_eq!(stdout(tmp.path(), &["--no-dotenv"]), "KEY=unset\n");
+}
+
+#[test]
+fn dotenv_load_setting_false() {
+  let tmp = tmptree! {
+    ".env": "KEY=DOTENV",
+    justfile: "set dotenv-load := false\n\ndefault:\n\t@echo KEY=${KEY:-unset}",
+  };
+
+  assert_eq!(stdout(tmp.path(), &[]), "KEY=unset\n");
+}
+
+#[test]
+fn dotenv_load_flag_overrides_setting() {
+  let tmp = tmptree! {
+    ".env": "KEY=DOTENV",
+    justfile: "set dotenv-load := false\n\ndefault:\n\t@echo KEY=${KEY:-unset}",
+  };
+
+  assert_eq!(stdout(tmp.path(), &["--dotenv-load"]), "KEY=DOTENV\n");
+}
+
+#[test]
+fn no_dotenv_flag_overrides_setting() {
+  let tmp = tmptree! {
+    ".env": "KEY=DOTENV",
+    justfile: "set dotenv-load\n\ndefault:\n\t@echo KEY=${KEY:-unset}",
+  };
+
+  assert_eq!(stdout(tmp.path(), &["--no-dotenv"]), "KEY=unset\n");
+}
+
+#[test]
+fn dotenv_filename_setting() {
+  let tmp = tmptree! {
+    ".env": "KEY=DOTENV",
+    ".env.local": "KEY=LOCAL",
+    justfile: "set dotenv-filename := '.env.local'\n\ndefault:\n\t@echo KEY=${KEY:-unset}",
+  };
+
+  assert_eq!(stdout(tmp.path(), &[]), "KEY=LOCAL\n");
+}
+
+#[test]
+fn dotenv_filename_searches_ancestors() {
+  let tmp = tmptree! {
+    ".env.local": "KEY=ROOT",
+    sub: {
+      ".env": "KEY=SUB",
+      justfile: "set dotenv-filename := '.env.local'\n\ndefault:\n\t@echo KEY=${KEY:-unset}",
+    },
+  };
+
+  assert_eq!(stdout(&tmp.path().join("sub"), &[]), "KEY=ROOT\n");
+}
+
+#[test]
+fn dotenv_filename_flag_overrides_setting() {
+  let tmp = tmptree! {
+    ".env.local": "KEY=LOCAL",
+    ".env.test": "KEY=TEST",
+    justfile: "set dotenv-filename := '.env.local'\n\ndefault:\n\t@echo KEY=${KEY:-unset}",
+  };
+
+  assert_eq!(
+    stdout(tmp.path(), &["--dotenv-filename", ".env.test"]),
+    "KEY=TEST\n"
+  );
+}
+
+#[test]
+fn dotenv_path_setting() {
+  let tmp = tmptree! {
+    ".env": "KEY=DOTENV",
+    config: {
+      env: "KEY=CONFIG",
+    },
+    justfile: "set dotenv-path := 'config/env'\n\ndefault:\n\t@echo KEY=${KEY:-unset}",
+  };
+
+  assert_eq!(stdout(tmp.path(), &[]), "KEY=CONFIG\n");
+}
+
+#[test]
+fn dotenv_path_setting_is_relative_to_working_directory() {
+  let tmp = tmptree! {
+    config: {
+      env: "KEY=CONFIG",
+    },
+    justfile: "set dotenv-path := 'config/env'\n\ndefault:\n\t@echo KEY=${KEY:-unset}",
+    sub: {},
+  };
+
+  assert_eq!(stdout(&tmp.path().join("sub"), &[]), "KEY=CONFIG\n");
+}
+
+#[test]
+fn dotenv_path_later_files_take_precedence() {
+  let tmp = tmptree! {
+    ".env": "A=BASE\nB=BASE",
+    ".env.local": "B=LOCAL",
+    justfile: "
+set dotenv-path := ['.env', '.env.local']
+
+default:
+\t@echo $A $B",
+  };
+
+  assert_eq!(stdout(tmp.path(), &[]), "BASE LOCAL\n");
+}
+
+#[test]
+fn dotenv_path_flag_overrides_setting() {
+  let tmp = tmptree! {
+    a: "KEY=A\nOTHER=A",
+    b: "KEY=B",
+    c: "KEY=C",
+    justfile: "set dotenv-path := 'c'\n\ndefault:\n\t@echo $KEY $OTHER",
+  };
+
+  assert_eq!(
+    stdout(tmp.path(), &["--dotenv-path", "a", "--dotenv-path", "b"]),
+    "B A\n"
+  );
+}
+
+#[test]
+fn dotenv_path_missing() {
+  let tmp = tmptree! {
+    justfile: "set dotenv-path := 'missing'\n\ndefault:\n\t@echo KEY=${KEY:-unset}",
+  };
+
+  let output = run(tmp.path(), &[]);
+
+  assert_eq!(output.status.code().unwrap(), 1);
+
+  let stderr = str::from_utf8(&output.stderr).unwrap();
+
+  assert!(
+    stderr.starts_with(&format!(
+      "error: Failed to load environment file `{}`:",
+      tmp.path().join("missing").display()
+    )),
+    "unexpected stderr: {}",
+    stderr
+  );
+}
+
+#[test]
+fn environment_takes_precedence_over_dotenv() {
+  let tmp = tmptree! {
+    ".env": "KEY=DOTENV",
+    justfile: JUSTFILE_ECHO,
+  };
+
+  let output = process::Command::new(executable_path("just"))
+    .current_dir(tmp.path())
+    .env("KEY", "ENVIRONMENT")
+    .output()
+    .expect("just invocation failed");
+
+  assert_eq!(str::from_utf8(&output.stdout).unwrap(), "KEY=ENVIRONMENT\n");
+}
+
+#[test]
+fn verbose_reports_dotenv_files() {
+  let tmp = tmptree! {
+    ".env": "A=BASE",
+    ".env.local": "B=LOCAL",
+    justfile: "set dotenv-path := ['.env', '.env.local']\n\ndefault:\n\t@echo $A $B",
+  };
+
+  let output = run(tmp.path(), &["--verbose"]);
+
+  assert_eq!(output.status.code().unwrap(), 0);
+
+  assert_eq!(
+    str::from_utf8(&output.stderr).unwrap(),
+    "===> Loading environment file `.env`...\n===> Loading environment file `.env.local`...\n===> \
+     Running recipe `default`...\necho $A $B\n"
+  );
+}
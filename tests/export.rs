@@ -0,0 +1,139 @@
+test! {
+  name:     setting,
+  justfile: "
+    set export
+
+    A := 'hello'
+
+    foo B C=`echo $A`:
+      echo $A
+      echo $B
+      echo $C
+  ",
+  args:     ("foo", "goodbye"),
+  stdout:   "hello\ngoodbye\nhello\n",
+  stderr:   "echo $A\necho $B\necho $C\n",
+}
+
+test! {
+  name:     setting_global_backtick,
+  justfile: "
+    set export
+
+    a := 'hello'
+    b := `echo $a`
+
+    foo:
+      @echo {{b}}
+  ",
+  stdout:   "hello\n",
+}
+
+test! {
+  name:     setting_local_backtick,
+  justfile: "
+    set export
+
+    foo x:
+      let a := 'hello'
+      let b := `echo $a $x`
+      @echo {{b}}
+  ",
+  args:     ("foo", "world"),
+  stdout:   "hello world\n",
+}
+
+test! {
+  name:     setting_false,
+  justfile: r#"
+    set export := false
+
+    A := 'hello'
+
+    foo:
+      @echo "[${A:-}]"
+  "#,
+  stdout:   "[]\n",
+}
+
+test! {
+  name:     setting_shebang,
+  justfile: "
+    set export
+
+    A := 'hello'
+
+    foo B:
+      #!/bin/sh
+      echo $A
+      echo $B
+  ",
+  args:     ("foo", "goodbye"),
+  stdout:   "hello\ngoodbye\n",
+}
+
+test! {
+  name:     setting_locals,
+  justfile: "
+    set export
+
+    foo:
+      let A := 'hello'
+      @echo $A
+  ",
+  stdout:   "hello\n",
+}
+
+test! {
+  name:     setting_recipe_backtick,
+  justfile: "
+    set export
+
+    A := 'hello'
+
+    foo:
+      @echo {{`echo $A`}}
+  ",
+  stdout:   "hello\n",
+}
+
+test! {
+  name:     parameter,
+  justfile: r#"
+    foo $bar baz:
+      @echo $bar "[${baz:-}]"
+  "#,
+  args:     ("foo", "hello", "goodbye"),
+  stdout:   "hello []\n",
+}
+
+test! {
+  name:     parameter_variadic,
+  justfile: "
+    foo +$bar:
+      @echo $bar
+  ",
+  args:     ("foo", "a", "b"),
+  stdout:   "a b\n",
+}
+
+test! {
+  name:     parameter_default_backtick,
+  justfile: "
+    foo $bar baz=`echo ${bar:-unset}`:
+      @echo {{baz}}
+  ",
+  args:     ("foo", "hello"),
+  stdout:   "unset\n",
+}
+
+test! {
+  name:     parameter_dump,
+  justfile: "
+    foo $bar +$baz='a':
+  ",
+  args:     ("--dump"),
+  stdout:   "
+    foo $bar +$baz='a':
+  ",
+}
@@ -0,0 +1,287 @@
+use crate::common::*;
+
+#[test]
+fn runs_recipe_in_parent_justfile() {
+  let tmp = tmptree! {
+    justfile: "
+foo:
+  @echo root
+",
+    sub: {
+      justfile: "
+set fallback
+
+bar:
+  @echo sub
+",
+    },
+  };
+
+  let (status, stdout, stderr) = run(&tmp.path().join("sub"), &["foo"]);
+
+  assert_eq!(status, Some(EXIT_SUCCESS), "{}", stderr);
+  assert_eq!(stdout, "root\n");
+}
+
+#[test]
+fn recipe_in_current_justfile_is_preferred() {
+  let tmp = tmptree! {
+    justfile: "
+foo:
+  @echo root
+",
+    sub: {
+      justfile: "
+set fallback
+
+foo:
+  @echo sub
+",
+    },
+  };
+
+  let (status, stdout, stderr) = run(&tmp.path().join("sub"), &["foo"]);
+
+  assert_eq!(status, Some(EXIT_SUCCESS), "{}", stderr);
+  assert_eq!(stdout, "sub\n");
+}
+
+#[test]
+fn disabled_by_default() {
+  let tmp = tmptree! {
+    justfile: "
+foo:
+  @echo root
+",
+    sub: {
+      justfile: "
+bar:
+  @echo sub
+",
+    },
+  };
+
+  let (status, stdout, stderr) = run(&tmp.path().join("sub"), &["foo"]);
+
+  assert_eq!(status, Some(EXIT_FAILURE));
+  assert_eq!(stdout, "");
+  assert_eq!(stderr, "error: Justfile does not contain recipe `foo`.\n");
+}
+
+#[test]
+fn parent_working_directory_is_used() {
+  let tmp = tmptree! {
+    justfile: "
+foo:
+  @cat data
+",
+    data: "root",
+    sub: {
+      justfile: "
+set fallback
+
+bar:
+  @echo sub
+",
+      data: "sub",
+    },
+  };
+
+  let (status, stdout, stderr) = run(&tmp.path().join("sub"), &["foo"]);
+
+  assert_eq!(status, Some(EXIT_SUCCESS), "{}", stderr);
+  assert_eq!(stdout, "root");
+}
+
+#[test]
+fn multiple_levels() {
+  let tmp = tmptree! {
+    justfile: "
+foo:
+  @echo root
+",
+    a: {
+      justfile: "
+set fallback
+
+bar:
+  @echo a
+",
+      b: {
+        justfile: "
+set fallback
+
+baz:
+  @echo b
+",
+      },
+    },
+  };
+
+  let (status, stdout, stderr) = run(&tmp.path().join("a/b"), &["foo"]);
+
+  assert_eq!(status, Some(EXIT_SUCCESS), "{}", stderr);
+  assert_eq!(stdout, "root\n");
+}
+
+#[test]
+fn stops_at_justfile_without_fallback() {
+  let tmp = tmptree! {
+    justfile: "
+foo:
+  @echo root
+",
+    a: {
+      justfile: "
+bar:
+  @echo a
+",
+      b: {
+        justfile: "
+set fallback
+
+baz:
+  @echo b
+",
+      },
+    },
+  };
+
+  let (status, stdout, stderr) = run(&tmp.path().join("a/b"), &["foo"]);
+
+  assert_eq!(status, Some(EXIT_FAILURE));
+  assert_eq!(stdout, "");
+  assert_eq!(stderr, "error: Justfile does not contain recipe `foo`.\n");
+}
+
+#[test]
+fn all_recipes_must_be_in_same_justfile() {
+  let tmp = tmptree! {
+    justfile: "
+foo:
+  @echo root-foo
+
+bar:
+  @echo root-bar
+",
+    sub: {
+      justfile: "
+set fallback
+
+bar:
+  @echo sub-bar
+",
+    },
+  };
+
+  let (status, stdout, stderr) = run(&tmp.path().join("sub"), &["foo", "bar"]);
+
+  assert_eq!(status, Some(EXIT_SUCCESS), "{}", stderr);
+  assert_eq!(stdout, "root-foo\nroot-bar\n");
+}
+
+#[test]
+fn parent_compilation_error_is_reported() {
+  let tmp = tmptree! {
+    justfile: "
+foo:
+  @echo {{bar}}
+",
+    sub: {
+      justfile: "
+set fallback
+
+baz:
+  @echo sub
+",
+    },
+  };
+
+  let (status, stdout, stderr) = run(&tmp.path().join("sub"), &["foo"]);
+
+  assert_eq!(status, Some(EXIT_FAILURE));
+  assert_eq!(stdout, "");
+  assert!(
+    stderr.starts_with("error: Variable `bar` not defined"),
+    "{}",
+    stderr
+  );
+}
+
+#[test]
+fn other_errors_do_not_fall_back() {
+  let tmp = tmptree! {
+    justfile: "
+foo:
+  @echo root
+",
+    sub: {
+      justfile: "
+set fallback
+
+foo:
+  @exit 3
+",
+    },
+  };
+
+  let (status, stdout, stderr) = run(&tmp.path().join("sub"), &["foo"]);
+
+  assert_eq!(status, Some(3));
+  assert_eq!(stdout, "");
+  assert_eq!(
+    stderr,
+    "error: Recipe `foo` failed on line 5 with exit code 3\n"
+  );
+}
+
+#[test]
+fn justfile_is_not_evaluated_before_falling_back() {
+  let tmp = tmptree! {
+    justfile: "
+foo:
+  @echo root
+",
+    sub: {
+      justfile: "
+set fallback
+
+x := `touch evaluated`
+
+bar:
+  @echo {{x}}
+",
+    },
+  };
+
+  let (status, stdout, stderr) = run(&tmp.path().join("sub"), &["foo"]);
+
+  assert_eq!(status, Some(EXIT_SUCCESS), "{}", stderr);
+  assert_eq!(stdout, "root\n");
+  assert!(!tmp.path().join("sub/evaluated").exists());
+}
+
+#[test]
+fn overrides_apply_to_parent_justfile() {
+  let tmp = tmptree! {
+    justfile: "
+x := 'root'
+
+foo:
+  @echo {{x}}
+",
+    sub: {
+      justfile: "
+set fallback
+
+bar:
+  @echo sub
+",
+    },
+  };
+
+  let (status, stdout, stderr) = run(&tmp.path().join("sub"), &["--set", "x", "override", "foo"]);
+
+  assert_eq!(status, Some(EXIT_SUCCESS), "{}", stderr);
+  assert_eq!(stdout, "override\n");
+}
@@ -0,0 +1,151 @@
+use crate::common::*;
+
+test! {
+  name:     interpolation,
+  justfile: "
+    version := '1.0'
+    a := f'{{version}}-{{os()}}'
+
+    foo:
+      @echo {{a}}
+  ",
+  stdout:   format!("1.0-{}\n", env::consts::OS).as_str(),
+}
+
+test! {
+  name:     expression,
+  justfile: "
+    a := f'{{ 'b' + 'c' }}-{{ if 'x' == 'x' { 'd' } else { 'e' } }}'
+
+    foo:
+      @echo {{a}}
+  ",
+  stdout:   "bc-d\n",
+}
+
+test! {
+  name:     cooked_escapes,
+  justfile: r#"
+    a := f"b\tc{{'d'}}"
+
+    foo:
+      @printf '%s\n' "{{a}}"
+  "#,
+  stdout:   "b\tcd\n",
+}
+
+test! {
+  name:     raw_escapes,
+  justfile: r#"
+    a := f'b\tc{{"d"}}'
+
+    foo:
+      @printf '%s\n' '{{a}}'
+  "#,
+  stdout:   "b\\tcd\n",
+}
+
+test! {
+  name:     nested,
+  justfile: "
+    b := 'c'
+    a := f'x{{ f\"y{{b}}\" }}z'
+
+    foo:
+      @echo {{a}}
+  ",
+  stdout:   "xycz\n",
+}
+
+test! {
+  name:     recipe_body,
+  justfile: "
+    foo bar:
+      @echo {{ f'[{{bar}}]' }}
+  ",
+  args:     ("foo", "baz"),
+  stdout:   "[baz]\n",
+}
+
+test! {
+  name:     parameter_default,
+  justfile: "
+    a := 'b'
+
+    foo bar=f'{{a}}c':
+      @echo {{bar}}
+  ",
+  stdout:   "bc\n",
+}
+
+test! {
+  name:     escaped_interpolation,
+  justfile: r#"
+    b := 'c'
+    a := f"{{{{b}}={{b}}"
+
+    foo:
+      @echo '{{a}}'
+  "#,
+  stdout:   "{{b}}=c\n",
+}
+
+test! {
+  name:     dump,
+  justfile: r#"
+    a := f"b{{c}}\n"
+    c := 'd'
+  "#,
+  args:     ("--dump"),
+  stdout:   r#"
+    a := f"b{{c}}\n"
+
+    c := 'd'
+  "#,
+}
+
+test! {
+  name:     undefined_variable,
+  justfile: "
+    a := f'{{b}}'
+
+    foo:
+      echo {{ a }}
+  ",
+  stderr:   "
+    error: Variable `b` not defined
+      |
+    1 | a := f'{{b}}'
+      |          ^
+  ",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     undefined_variable_in_recipe_body,
+  justfile: "
+    foo:
+      echo {{ f'{{b}}' }}
+  ",
+  stderr:   "
+    error: Variable `b` not defined
+      |
+    2 |   echo {{ f'{{b}}' }}
+      |               ^
+  ",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     unterminated,
+  justfile: "
+    a := f'{{b}}
+  ",
+  stderr:   "
+    error: Unterminated string
+      |
+    1 | a := f'{{b}}
+      |      ^^
+  ",
+  status:   EXIT_FAILURE,
+}
@@ -0,0 +1,244 @@
+use crate::common::*;
+
+test! {
+  name:     path_functions,
+  justfile: r#"
+    we  := without_extension('/foo/bar/baz.hello')
+    fs  := file_stem('/foo/bar/baz.hello')
+    fn  := file_name('/foo/bar/baz.hello')
+    dir := parent_directory('/foo/bar/baz.hello')
+    ext := extension('/foo/bar/baz.hello')
+    jn  := join('a', 'b')
+
+    foo:
+      /bin/echo '{{we}}' '{{fs}}' '{{fn}}' '{{dir}}' '{{ext}}' '{{jn}}'
+  "#,
+  stdout:   "/foo/bar/baz baz baz.hello /foo/bar hello a/b\n",
+  stderr:   "/bin/echo '/foo/bar/baz' 'baz' 'baz.hello' '/foo/bar' 'hello' 'a/b'\n",
+}
+
+test! {
+  name:     path_functions_relative,
+  justfile: r#"
+    we  := without_extension('bar/baz.hello')
+    fs  := file_stem('baz.hello')
+    dir := parent_directory('bar/baz.hello')
+    jn  := join('a/', '/b')
+
+    foo:
+      /bin/echo '{{we}}' '{{fs}}' '{{dir}}' '{{jn}}'
+  "#,
+  stdout:   "bar/baz baz bar /b\n",
+  stderr:   "/bin/echo 'bar/baz' 'baz' 'bar' '/b'\n",
+}
+
+test! {
+  name:     clean,
+  justfile: r#"
+    foo:
+      /bin/echo '{{clean('a/./b/../c')}}' '{{clean('../a/..')}}' '{{clean('/..')}}' '{{clean('a/..')}}'
+  "#,
+  stdout:   "a/c .. / .\n",
+  stderr:   "/bin/echo 'a/c' '..' '/' '.'\n",
+}
+
+test! {
+  name:     absolute_path,
+  justfile: r#"
+    foo:
+      @test '{{absolute_path('bar')}}' = "$PWD/bar"
+  "#,
+}
+
+test! {
+  name:     extension_missing,
+  justfile: r#"
+    foo:
+      /bin/echo '{{extension('/foo/bar/baz')}}'
+  "#,
+  stderr:   "
+    error: Call to function `extension` failed: Could not extract extension from `/foo/bar/baz`
+      |
+    2 |   /bin/echo '{{extension('/foo/bar/baz')}}'
+      |                ^^^^^^^^^
+  ",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     parent_directory_of_root,
+  justfile: r#"
+    foo:
+      /bin/echo '{{parent_directory('/')}}'
+  "#,
+  stderr:   "
+    error: Call to function `parent_directory` failed: Could not extract parent directory from `/`
+      |
+    2 |   /bin/echo '{{parent_directory('/')}}'
+      |                ^^^^^^^^^^^^^^^^
+  ",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     file_name_of_parent,
+  justfile: r#"
+    foo:
+      /bin/echo '{{file_name('foo/..')}}'
+  "#,
+  stderr:   "
+    error: Call to function `file_name` failed: Could not extract file name from `foo/..`
+      |
+    2 |   /bin/echo '{{file_name('foo/..')}}'
+      |                ^^^^^^^^^
+  ",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     join_variadic,
+  justfile: r#"
+    foo:
+      /bin/echo '{{join('a', 'b', 'c', 'd')}}' '{{join('a', 'b', '/c', 'd')}}'
+  "#,
+  stdout:   "a/b/c/d /c/d\n",
+  stderr:   "/bin/echo 'a/b/c/d' '/c/d'\n",
+}
+
+test! {
+  name:     join_too_few_arguments,
+  justfile: "x := join('a')",
+  stderr:   "
+    error: Function `join` called with 1 argument but takes at least 2
+      |
+    1 | x := join('a')
+      |      ^^^^
+  ",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     case_functions,
+  justfile: r#"
+    foo:
+      /bin/echo '{{uppercase('bAr')}}' '{{lowercase('BaZ')}}'
+  "#,
+  stdout:   "BAR baz\n",
+  stderr:   "/bin/echo 'BAR' 'baz'\n",
+}
+
+test! {
+  name:     trim_functions,
+  justfile: r#"
+    foo:
+      /bin/echo '{{trim('  a b  ')}}' '{{trim_start_match('aaab', 'a')}}' '{{trim_end_match('baaa', 'a')}}' '{{trim_end_match('b', 'a')}}'
+  "#,
+  stdout:   "a b aab baa b\n",
+  stderr:   "/bin/echo 'a b' 'aab' 'baa' 'b'\n",
+}
+
+test! {
+  name:     replace,
+  justfile: r#"
+    foo:
+      /bin/echo '{{replace('a-b-c', '-', '+')}}'
+  "#,
+  stdout:   "a+b+c\n",
+  stderr:   "/bin/echo 'a+b+c'\n",
+}
+
+test! {
+  name:     replace_regex,
+  justfile: r#"
+    foo:
+      /bin/echo '{{replace_regex('v1.2.3', '(\d+)\.(\d+)', '$2.$1')}}'
+  "#,
+  stdout:   "v2.1.3\n",
+  stderr:   "/bin/echo 'v2.1.3'\n",
+}
+
+test! {
+  name:     replace_regex_invalid,
+  justfile: r#"
+    foo:
+      /bin/echo '{{replace_regex('a', '(', 'b')}}'
+  "#,
+  stderr:   "
+    error: Call to function `replace_regex` failed: regex parse error:
+        (
+        ^
+    error: unclosed group
+      |
+    2 |   /bin/echo '{{replace_regex('a', '(', 'b')}}'
+      |                ^^^^^^^^^^^^^
+  ",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     replace_argument_count,
+  justfile: "x := replace('a', 'b')",
+  stderr:   "
+    error: Function `replace` called with 2 arguments but takes 3
+      |
+    1 | x := replace('a', 'b')
+      |      ^^^^^^^
+  ",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     sha256,
+  justfile: r#"
+    foo:
+      /bin/echo '{{sha256('5943ee37-0000-1000-8000-010203040506')}}'
+  "#,
+  stdout:   "2330d7f5eb94a820b54fed59a8eced236f80b633a504289c030b6a65aef58871\n",
+  stderr:   "/bin/echo '2330d7f5eb94a820b54fed59a8eced236f80b633a504289c030b6a65aef58871'\n",
+}
+
+test! {
+  name:     uuid,
+  justfile: r#"
+    foo:
+      @echo '{{uuid()}}' | grep -E '^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$' > /dev/null
+  "#,
+}
+
+#[test]
+fn sha256_file() {
+  let tmp = tmptree! {
+    justfile: "foo:\n  @echo {{sha256_file('sub/data')}}\n",
+    sub: {
+      data: "hello\n",
+    },
+  };
+
+  let output = Command::new(executable_path("just"))
+    .current_dir(tmp.path().join("sub"))
+    .output()
+    .expect("just invocation failed");
+
+  assert_eq!(
+    str::from_utf8(&output.stdout).unwrap(),
+    "5891b5b522d5df086d0ff0b110fbd9d21bb4fc7163af34d08286a2e846f6be03\n"
+  );
+  assert!(output.status.success());
+}
+
+#[test]
+fn sha256_file_missing() {
+  let tmp = tmptree! {
+    justfile: "foo:\n  @echo {{sha256_file('missing')}}\n",
+  };
+
+  let output = Command::new(executable_path("just"))
+    .current_dir(tmp.path())
+    .output()
+    .expect("just invocation failed");
+
+  assert!(str::from_utf8(&output.stderr)
+    .unwrap()
+    .starts_with("error: Call to function `sha256_file` failed: Failed to open file at `"));
+  assert_eq!(output.status.code(), Some(EXIT_FAILURE));
+}
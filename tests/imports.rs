@@ -0,0 +1,191 @@
+use crate::common::*;
+
+#[test]
+fn import_recipes_and_assignments() {
+  let tmp = tmptree! {
+    justfile: "
+import 'lint.just'
+
+default: lint
+  echo {{linter}}
+",
+    "lint.just": "
+linter := 'clippy'
+
+lint:
+  echo lint
+",
+  };
+
+  let (status, stdout, stderr) = run(tmp.path(), &[]);
+
+  assert_eq!(stderr, "echo lint\necho clippy\n");
+  assert_eq!(stdout, "lint\nclippy\n");
+  assert_eq!(status, Some(EXIT_SUCCESS));
+}
+
+#[test]
+fn imports_are_relative_to_importing_file() {
+  let tmp = tmptree! {
+    justfile: "import 'common/release.just'\n",
+    common: {
+      "release.just": "import 'version.just'\n\nrelease:\n  echo {{version}}\n",
+      "version.just": "version := '1.0.0'\n",
+    },
+  };
+
+  let (status, stdout, stderr) = run(tmp.path(), &["release"]);
+
+  assert_eq!(stderr, "echo 1.0.0\n");
+  assert_eq!(stdout, "1.0.0\n");
+  assert_eq!(status, Some(EXIT_SUCCESS));
+}
+
+#[test]
+fn default_recipe_is_first_in_root_justfile() {
+  let tmp = tmptree! {
+    justfile: "import 'other.just'\n\nfoo:\n  echo foo\n",
+    "other.just": "bar:\n  echo bar\n",
+  };
+
+  let (status, stdout, _) = run(tmp.path(), &[]);
+
+  assert_eq!(stdout, "foo\n");
+  assert_eq!(status, Some(EXIT_SUCCESS));
+}
+
+#[test]
+fn shared_import_is_loaded_once() {
+  let tmp = tmptree! {
+    justfile: "import 'a.just'\nimport 'b.just'\n\nall: a b\n",
+    "a.just": "import 'common.just'\n\na:\n  echo {{name}}-a\n",
+    "b.just": "import 'common.just'\n\nb:\n  echo {{name}}-b\n",
+    "common.just": "name := 'common'\n",
+  };
+
+  let (status, stdout, _) = run(tmp.path(), &[]);
+
+  assert_eq!(stdout, "common-a\ncommon-b\n");
+  assert_eq!(status, Some(EXIT_SUCCESS));
+}
+
+#[test]
+fn dump_inlines_imports() {
+  let tmp = tmptree! {
+    justfile: "import 'other.just'\n\nfoo:\n  echo foo\n",
+    "other.just": "bar := 'bar'\n",
+  };
+
+  let (status, stdout, _) = run(tmp.path(), &["--dump"]);
+
+  assert_eq!(stdout, "bar := 'bar'\n\nfoo:\n    echo foo\n");
+  assert_eq!(status, Some(EXIT_SUCCESS));
+}
+
+#[test]
+fn duplicate_recipe_in_import() {
+  let tmp = tmptree! {
+    justfile: "import 'other.just'\n\nfoo:\n  echo foo\n",
+    "other.just": "\nfoo:\n  echo bar\n",
+  };
+
+  let (status, _, stderr) = run(tmp.path(), &[]);
+
+  assert_eq!(
+    stderr,
+    unindent(
+      "
+      error: Recipe `foo` first defined on line 2 is redefined on line 3
+        |
+      3 | foo:
+        | ^^^
+      "
+    )
+  );
+  assert_eq!(status, Some(EXIT_FAILURE));
+}
+
+#[test]
+fn duplicate_variable_in_import_reports_file() {
+  let tmp = tmptree! {
+    justfile: "x := 'a'\n\nimport 'other.just'\n",
+    "other.just": "\nx := 'b'\n",
+  };
+
+  let (status, _, stderr) = run(tmp.path(), &[]);
+
+  assert_eq!(
+    stderr,
+    unindent(
+      "
+      error: Variable `x` has multiple definitions
+       --> other.just:2:1
+        |
+      2 | x := 'b'
+        | ^
+      "
+    )
+  );
+  assert_eq!(status, Some(EXIT_FAILURE));
+}
+
+#[test]
+fn circular_import() {
+  let tmp = tmptree! {
+    justfile: "import 'a.just'\n",
+    "a.just": "import 'b.just'\n",
+    "b.just": "import 'justfile'\n",
+  };
+
+  let (status, _, stderr) = run(tmp.path(), &[]);
+
+  assert_eq!(
+    stderr,
+    unindent(
+      "
+      error: Justfile `b.just` has circular import `justfile -> a.just -> b.just -> justfile`
+       --> b.just:1:8
+        |
+      1 | import 'justfile'
+        |        ^^^^^^^^^^
+      "
+    )
+  );
+  assert_eq!(status, Some(EXIT_FAILURE));
+}
+
+#[test]
+fn self_import() {
+  let tmp = tmptree! {
+    justfile: "import 'justfile'\n",
+  };
+
+  let (status, _, stderr) = run(tmp.path(), &[]);
+
+  assert_eq!(
+    stderr,
+    unindent(
+      "
+      error: Justfile `justfile` imports itself
+        |
+      1 | import 'justfile'
+        |        ^^^^^^^^^^
+      "
+    )
+  );
+  assert_eq!(status, Some(EXIT_FAILURE));
+}
+
+#[test]
+fn missing_import() {
+  let tmp = tmptree! {
+    justfile: "import 'missing.just'\n",
+  };
+
+  let (status, _, stderr) = run(tmp.path(), &[]);
+
+  assert!(stderr.starts_with(
+    "error: Failed to read imported justfile at `missing.just`: No such file or directory"
+  ));
+  assert_eq!(status, Some(EXIT_FAILURE));
+}
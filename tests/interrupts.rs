@@ -14,7 +14,7 @@ mod unix {
     }
   }
 
-  fn interrupt_test(justfile: &str) {
+  fn interrupt_test(arguments: &[&str], justfile: &str) {
     let tmp = tempdir();
     let mut justfile_path = tmp.path().to_path_buf();
     justfile_path.push("justfile");
@@ -24,6 +24,7 @@ mod unix {
 
     let mut child = Command::new(&executable_path("just"))
       .current_dir(&tmp)
+      .args(arguments)
       .spawn()
       .expect("just invocation failed");
 
@@ -50,6 +51,7 @@ mod unix {
   #[ignore]
   fn interrupt_shebang() {
     interrupt_test(
+      &[],
       "
 default:
   #!/usr/bin/env sh
@@ -62,6 +64,7 @@ default:
   #[ignore]
   fn interrupt_line() {
     interrupt_test(
+      &[],
       "
 default:
   @sleep 1
@@ -73,11 +76,29 @@ default:
   #[ignore]
   fn interrupt_backtick() {
     interrupt_test(
+      &[],
       "
 foo := `sleep 1`
 
 default:
   @echo {{foo}}
+",
+    );
+  }
+
+  #[test]
+  #[ignore]
+  fn interrupt_jobs() {
+    interrupt_test(
+      &["--jobs", "2"],
+      "
+default: foo bar
+
+foo:
+  @sleep 5
+
+bar:
+  @sleep 5
 ",
     );
   }
@@ -0,0 +1,243 @@
+use crate::common::*;
+
+test! {
+  name:     serial_by_default,
+  justfile: "
+    foo: bar baz
+      @echo foo
+
+    bar: qux
+      @echo bar
+
+    baz: qux
+      @echo baz
+
+    qux:
+      @echo qux
+  ",
+  stdout:   "
+    qux
+    bar
+    baz
+    foo
+  ",
+}
+
+test! {
+  name:     one_job_runs_serially,
+  justfile: "
+    foo: bar baz
+      @echo foo
+
+    bar: qux
+      @echo bar
+
+    baz:
+      @echo baz
+
+    qux:
+      @echo qux
+  ",
+  args:     ("--jobs", "1"),
+  stdout:   "
+    qux
+    bar
+    baz
+    foo
+  ",
+}
+
+test! {
+  name:     dependencies_run_concurrently,
+  justfile: "
+    foo: bar baz
+      @echo foo
+
+    bar:
+      @touch bar
+      @for i in $(seq 500); do test -f baz && exit 0; sleep 0.01; done; exit 1
+
+    baz:
+      @touch baz
+      @for i in $(seq 500); do test -f bar && exit 0; sleep 0.01; done; exit 1
+  ",
+  args:     ("--jobs", "2"),
+  stdout:   "foo\n",
+}
+
+test! {
+  name:     recipes_wait_for_dependencies,
+  justfile: "
+    foo: bar baz
+      @test -f bar
+      @test -f baz
+      @echo foo
+
+    bar:
+      @sleep 0.1
+      @touch bar
+
+    baz:
+      @sleep 0.1
+      @touch baz
+  ",
+  args:     ("--jobs", "4"),
+  stdout:   "foo\n",
+}
+
+test! {
+  name:     dependencies_run_once,
+  justfile: "
+    foo: bar baz
+      @cat qux
+
+    bar: qux
+    baz: qux
+
+    qux:
+      @echo qux >> qux
+  ",
+  args:     ("--jobs", "4"),
+  stdout:   "qux\n",
+}
+
+test! {
+  name:     subsequents_run_after_recipe,
+  justfile: "
+    foo: bar && baz
+      @test -f bar
+      @touch foo
+
+    bar:
+      @touch bar
+
+    baz:
+      @test -f foo
+      @echo baz
+  ",
+  args:     ("--jobs", "4"),
+  stdout:   "baz\n",
+}
+
+test! {
+  name:     dependents_wait_for_subsequents,
+  justfile: "
+    foo: bar
+      @test -f baz
+      @echo foo
+
+    bar: && baz
+
+    baz:
+      @sleep 0.1
+      @touch baz
+  ",
+  args:     ("--jobs", "4"),
+  stdout:   "foo\n",
+}
+
+test! {
+  name:     failure_kills_running_jobs,
+  justfile: "
+    foo: bar baz
+      @echo foo
+
+    bar:
+      @sleep 0.1
+      @false
+
+    baz:
+      @sleep 5
+      @echo baz
+  ",
+  args:     ("--jobs", "2"),
+  stderr:   "error: Recipe `bar` failed on line 6 with exit code 1\n",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     failure_prevents_new_jobs,
+  justfile: "
+    foo: bar baz qux
+      @echo foo
+
+    bar:
+      @false
+
+    baz:
+      @sleep 0.1
+
+    qux:
+      @echo qux
+  ",
+  args:     ("--jobs", "2"),
+  stderr:   "error: Recipe `bar` failed on line 5 with exit code 1\n",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     evaluation_precedes_execution,
+  justfile: "
+    foo: bar (baz `exit 1`)
+
+    bar:
+      @echo bar
+
+    baz arg:
+  ",
+  args:     ("--jobs", "2"),
+  stderr:   "
+    error: Backtick failed with exit code 1
+      |
+    1 | foo: bar (baz `exit 1`)
+      |               ^^^^^^^^
+  ",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     serial_parameters_evaluated_before_recipe_runs,
+  justfile: "
+    a:
+      @echo made > f
+
+    b x=`cat f`:
+      @echo {{x}}
+  ",
+  args:     ("a", "b"),
+  stdout:   "made\n",
+}
+
+test! {
+  name:     serial_dependency_arguments_evaluated_before_dependency_runs,
+  justfile: "
+    foo: bar (baz `cat f`)
+
+    bar:
+      @echo made > f
+
+    baz arg:
+      @echo {{arg}}
+  ",
+  stdout:   "made\n",
+}
+
+test! {
+  name:     serial_evaluation_error_after_execution,
+  justfile: "
+    foo: bar (baz `exit 1`)
+
+    bar:
+      @echo bar
+
+    baz arg:
+  ",
+  stdout:   "bar\n",
+  stderr:   "
+    error: Backtick failed with exit code 1
+      |
+    1 | foo: bar (baz `exit 1`)
+      |               ^^^^^^^^
+  ",
+  status:   EXIT_FAILURE,
+}
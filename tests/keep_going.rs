@@ -0,0 +1,220 @@
+use crate::common::*;
+
+test! {
+  name:     stops_at_first_failure_by_default,
+  justfile: "
+    lint:
+      @exit 3
+
+    test:
+      @echo test
+  ",
+  args:     ("lint", "test"),
+  stderr:   "error: Recipe `lint` failed on line 2 with exit code 3\n",
+  status:   3,
+}
+
+test! {
+  name:     runs_remaining_recipes,
+  justfile: "
+    lint:
+      @exit 3
+
+    test:
+      @echo test
+
+    docs:
+      @echo docs
+  ",
+  args:     ("--keep-going", "lint", "test", "docs"),
+  stdout:   "
+    test
+    docs
+  ",
+  stderr:   "
+    ===> Summary
+    lint failed
+    test passed
+    docs passed
+    error: Recipe `lint` failed on line 2 with exit code 3
+  ",
+  status:   3,
+}
+
+test! {
+  name:     all_passed,
+  justfile: "
+    lint:
+      @echo lint
+
+    test:
+      @echo test
+  ",
+  args:     ("--keep-going", "lint", "test"),
+  stdout:   "
+    lint
+    test
+  ",
+  stderr:   "
+    ===> Summary
+    lint passed
+    test passed
+  ",
+}
+
+test! {
+  name:     dependents_of_failed_recipes_are_skipped,
+  justfile: "
+    ci: build lint
+      @echo ci
+
+    build:
+      @exit 1
+
+    lint:
+      @echo lint
+
+    package: build
+      @echo package
+
+    publish: package
+      @echo publish
+  ",
+  args:     ("--keep-going", "ci", "publish"),
+  stdout:   "lint\n",
+  stderr:   "
+    ===> Summary
+    build   failed
+    lint    passed
+    ci      skipped
+    package skipped
+    publish skipped
+    error: Recipe `build` failed on line 5 with exit code 1
+  ",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     subsequents_of_failed_recipes_are_skipped,
+  justfile: "
+    foo: && bar
+      @exit 1
+
+    bar:
+      @echo bar
+
+    baz:
+      @echo baz
+  ",
+  args:     ("--keep-going", "foo", "baz"),
+  stdout:   "baz\n",
+  stderr:   "
+    ===> Summary
+    foo failed
+    bar skipped
+    baz passed
+    error: Recipe `foo` failed on line 2 with exit code 1
+  ",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     every_error_is_reported,
+  justfile: "
+    lint:
+      @exit 3
+
+    test:
+      @exit 4
+
+    docs:
+      @echo docs
+  ",
+  args:     ("--keep-going", "lint", "test", "docs"),
+  stdout:   "docs\n",
+  stderr:   "
+    ===> Summary
+    lint failed
+    test failed
+    docs passed
+    error: Recipe `lint` failed on line 2 with exit code 3
+    error: Recipe `test` failed on line 5 with exit code 4
+  ",
+  status:   3,
+}
+
+test! {
+  name:     arguments_are_shown,
+  justfile: "
+    build target:
+      @test {{target}} = release
+  ",
+  args:     ("--keep-going", "build", "debug", "build", "release"),
+  stderr:   "
+    ===> Summary
+    build debug   failed
+    build release passed
+    error: Recipe `build` failed on line 2 with exit code 1
+  ",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     no_exit_message,
+  justfile: "
+    [no-exit-message]
+    lint:
+      @exit 3
+
+    test:
+      @exit 4
+  ",
+  args:     ("--keep-going", "lint", "test"),
+  stderr:   "
+    ===> Summary
+    lint failed
+    test failed
+    error: Recipe `test` failed on line 6 with exit code 4
+  ",
+  status:   3,
+}
+
+test! {
+  name:     quiet,
+  justfile: "
+    lint:
+      @exit 3
+
+    test:
+      @echo test
+  ",
+  args:     ("--quiet", "--keep-going", "lint", "test"),
+  status:   3,
+}
+
+test! {
+  name:     concurrent,
+  justfile: "
+    ci: lint test
+      @echo ci
+
+    lint:
+      @exit 3
+
+    test:
+      @true
+
+    docs:
+      @true
+  ",
+  args:     ("--keep-going", "--jobs", "2", "ci", "docs"),
+  stderr:   "
+    ===> Summary
+    lint failed
+    test passed
+    ci   skipped
+    docs passed
+    error: Recipe `lint` failed on line 5 with exit code 3
+  ",
+  status:   3,
+}
@@ -3,6 +3,8 @@ mod test;
 
 mod common;
 
+mod attributes;
+mod cache;
 mod choose;
 mod completions;
 mod conditional;
@@ -11,11 +13,28 @@ mod dotenv;
 mod edit;
 mod error_messages;
 mod examples;
+mod export;
+mod fallback;
+mod format_string;
+mod functions;
+mod imports;
 mod init;
 mod interrupts;
 mod invocation_directory;
+mod jobs;
+mod keep_going;
+mod locals;
 mod misc;
+mod modules;
+mod positional_arguments;
 mod readme;
+mod retry;
 mod search;
 mod shell;
+mod slash_operator;
+mod string;
+mod subsequents;
+mod timeout;
+mod up_to_date;
+mod watch;
 mod working_directory;
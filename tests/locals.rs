@@ -0,0 +1,219 @@
+use crate::common::*;
+
+test! {
+  name:     interpolation,
+  justfile: "
+    foo:
+      let bar := 'baz'
+      @echo {{bar}}
+  ",
+  stdout:   "baz\n",
+}
+
+test! {
+  name:     parameters_and_variables,
+  justfile: "
+    a := 'A'
+
+    foo b:
+      let c := a + b
+      let d := c + '!'
+      @echo {{d}}
+  ",
+  args:     ("foo", "B"),
+  stdout:   "AB!\n",
+}
+
+test! {
+  name:     dependency_argument,
+  justfile: "
+    foo:
+      let bar := 'baz'
+      @echo {{bar}}
+
+    foo2: (bar local)
+      let local := 'hello'
+
+    bar x:
+      @echo {{x}}
+  ",
+  args:     ("foo2"),
+  stdout:   "hello\n",
+}
+
+test! {
+  name:     separated_by_blank_line,
+  justfile: "
+    foo:
+      let a := 'x'
+
+      let b := 'y'
+      @echo {{a}}{{b}}
+  ",
+  stdout:   "xy\n",
+}
+
+test! {
+  name:     not_evaluated_globally,
+  justfile: "
+    a := 'b'
+
+    foo:
+      let c := 'd'
+      echo {{c}}
+  ",
+  args:     ("--evaluate"),
+  stdout:   "a := \"b\"\n",
+}
+
+test! {
+  name:     error_line_number,
+  justfile: "
+    foo:
+      let bar := '10'
+      let bob := '0'
+      @exit {{bar}}{{bob}}
+  ",
+  stderr:   "error: Recipe `foo` failed on line 4 with exit code 100\n",
+  status:   100,
+}
+
+test! {
+  name:     error_line_number_after_multi_line_local,
+  justfile: "
+    foo:
+      let bar := '''
+        baz
+      '''
+      @echo {{bar}}
+      @exit 100
+  ",
+  stdout:   "baz\n",
+  stderr:   "error: Recipe `foo` failed on line 6 with exit code 100\n",
+  status:   100,
+}
+
+test! {
+  name:     error_line_number_after_blank_line,
+  justfile: "
+    foo:
+      let bar := '10'
+
+      let bob := '0'
+      @exit {{bar}}{{bob}}
+  ",
+  stderr:   "error: Recipe `foo` failed on line 5 with exit code 100\n",
+  status:   100,
+}
+
+test! {
+  name:     shebang_line_number_after_multi_line_local,
+  justfile: "
+    foo:
+      let bar := '''
+        baz
+      '''
+      #!/usr/bin/env bash
+      echo $LINENO
+      : {{bar}}
+  ",
+  stdout:   "6\n",
+}
+
+test! {
+  name:     shebang,
+  justfile: "
+    foo:
+      let bar := 'baz'
+      #!/usr/bin/env sh
+      echo {{bar}}
+  ",
+  stdout:   "baz\n",
+}
+
+test! {
+  name:     not_local_after_command,
+  justfile: "
+    foo:
+      echo
+      let bar := 'baz'
+  ",
+  args:     ("--dry-run"),
+  stderr:   "echo\nlet bar := 'baz'\n",
+}
+
+test! {
+  name:     dump,
+  justfile: "
+    foo:
+      let bar := 'baz' # comment
+      echo {{bar}}
+  ",
+  args:     ("--dump"),
+  stdout:   "
+    foo:
+        let bar := 'baz'
+        echo {{bar}}
+  ",
+}
+
+test! {
+  name:     undefined_variable,
+  justfile: "
+    foo:
+      let bar := baz
+  ",
+  stderr:   "
+    error: Variable `baz` not defined
+      |
+    2 |   let bar := baz
+      |              ^^^
+  ",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     shadows_variable,
+  justfile: "
+    bar := 'a'
+
+    foo:
+      let bar := 'b'
+  ",
+  stderr:   "
+    error: Variable `bar` has multiple definitions
+      |
+    4 |   let bar := 'b'
+      |       ^^^
+  ",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     unused,
+  justfile: "
+    foo:
+      let bar := 'baz'
+      @echo foo
+  ",
+  stdout:   "foo\n",
+  stderr:   "
+    warning: Recipe-local variable `bar` is never used
+    `let` lines at the start of a recipe body are recipe-local assignments, not commands
+      |
+    2 |   let bar := 'baz'
+      |       ^^^
+  ",
+}
+
+test! {
+  name:     unused_exported,
+  justfile: "
+    set export
+
+    foo:
+      let bar := 'baz'
+      @echo $bar
+  ",
+  stdout:   "baz\n",
+}
@@ -151,7 +151,7 @@ test! {
   justfile: "default:\n @echo hello",
   args:     ("--verbose"),
   stdout:   "hello\n",
-  stderr:   "===> Running recipe `default`...\necho hello\n",
+  stderr:   "===> Loading environment file `.env`...\n===> Running recipe `default`...\necho hello\n",
 }
 
 test! {
@@ -1490,7 +1490,7 @@ test! {
   justfile: "foo: 'bar'",
   args:     ("foo"),
   stdout:   "",
-  stderr:   "error: Expected comment, end of file, end of line, \
+  stderr:   "error: Expected '&&', comment, end of file, end of line, \
     identifier, or '(', but found raw string
   |
 1 | foo: 'bar'
@@ -1504,7 +1504,7 @@ test! {
   justfile: "foo 'bar'",
   args:     ("foo"),
   stdout:   "",
-  stderr:   "error: Expected '*', ':', identifier, or '+', but found raw string
+  stderr:   "error: Expected '*', ':', '$', identifier, or '+', but found raw string
   |
 1 | foo 'bar'
   |     ^^^^^
@@ -1881,7 +1881,7 @@ a:
 ",
   args:     ("--color", "always", "--highlight", "--verbose"),
   stdout:   "hi\n",
-  stderr:   "\u{1b}[1;36m===> Running recipe `a`...\u{1b}[0m\n\u{1b}[1mecho hi\u{1b}[0m\n",
+  stderr:   "\u{1b}[1;36m===> Loading environment file `.env`...\u{1b}[0m\n\u{1b}[1;36m===> Running recipe `a`...\u{1b}[0m\n\u{1b}[1mecho hi\u{1b}[0m\n",
 }
 
 test! {
@@ -1893,7 +1893,7 @@ a:
 ",
   args:     ("--color", "always", "--highlight", "--no-highlight", "--verbose"),
   stdout:   "hi\n",
-  stderr:   "\u{1b}[1;36m===> Running recipe `a`...\u{1b}[0m\necho hi\n",
+  stderr:   "\u{1b}[1;36m===> Loading environment file `.env`...\u{1b}[0m\n\u{1b}[1;36m===> Running recipe `a`...\u{1b}[0m\necho hi\n",
 }
 
 test! {
@@ -2138,7 +2138,7 @@ assembly_source_files = $(wildcard src/arch/$(arch)/*.s)
     error: Unknown start of token:
       |
     2 | assembly_source_files = $(wildcard src/arch/$(arch)/*.s)
-      |                         ^
+      |                                                      ^
   "#,
    status:   EXIT_FAILURE,
 }
@@ -0,0 +1,248 @@
+use crate::common::*;
+
+#[test]
+fn module_recipe() {
+  let tmp = tmptree! {
+    justfile: "mod docker\n",
+    "docker.just": "build:\n  @echo building\n",
+  };
+
+  let (status, stdout, stderr) = run(tmp.path(), &["docker::build"]);
+
+  assert_eq!(stderr, "");
+  assert_eq!(stdout, "building\n");
+  assert_eq!(status, Some(EXIT_SUCCESS));
+}
+
+#[test]
+fn module_directory() {
+  let tmp = tmptree! {
+    justfile: "mod docker\n",
+    docker: {
+      justfile: "build:\n  @echo building\n",
+    },
+  };
+
+  let (status, stdout, _) = run(tmp.path(), &["docker::build"]);
+
+  assert_eq!(stdout, "building\n");
+  assert_eq!(status, Some(EXIT_SUCCESS));
+}
+
+#[test]
+fn module_working_directory() {
+  let tmp = tmptree! {
+    justfile: "mod docker\n",
+    docker: {
+      justfile: "pwd:\n  @basename `pwd`\n",
+    },
+  };
+
+  let (status, stdout, _) = run(tmp.path(), &["docker::pwd"]);
+
+  assert_eq!(stdout, "docker\n");
+  assert_eq!(status, Some(EXIT_SUCCESS));
+}
+
+#[test]
+fn module_namespaces_assignments() {
+  let tmp = tmptree! {
+    justfile: "
+mod docker
+
+name := 'root'
+
+foo:
+  @echo {{name}}
+",
+    "docker.just": "
+name := 'docker'
+
+foo:
+  @echo {{name}}
+",
+  };
+
+  let (status, stdout, _) = run(tmp.path(), &["foo", "docker::foo"]);
+
+  assert_eq!(stdout, "root\ndocker\n");
+  assert_eq!(status, Some(EXIT_SUCCESS));
+}
+
+#[test]
+fn module_arguments() {
+  let tmp = tmptree! {
+    justfile: "mod docker\n",
+    "docker.just": "build tag:\n  @echo {{tag}}\n",
+  };
+
+  let (status, stdout, _) = run(tmp.path(), &["docker::build", "latest"]);
+
+  assert_eq!(stdout, "latest\n");
+  assert_eq!(status, Some(EXIT_SUCCESS));
+}
+
+#[test]
+fn nested_modules() {
+  let tmp = tmptree! {
+    justfile: "mod a\n",
+    a: {
+      justfile: "mod b\n",
+      "b.just": "c:\n  @echo c\n",
+    },
+  };
+
+  let (status, stdout, _) = run(tmp.path(), &["a::b::c"]);
+
+  assert_eq!(stdout, "c\n");
+  assert_eq!(status, Some(EXIT_SUCCESS));
+}
+
+#[test]
+fn module_dependency() {
+  let tmp = tmptree! {
+    justfile: "
+mod docker
+
+deploy: docker::build (docker::push 'latest')
+  @echo deploy
+",
+    "docker.just": "
+build:
+  @echo build
+
+push tag: build
+  @echo push {{tag}}
+",
+  };
+
+  let (status, stdout, _) = run(tmp.path(), &["deploy"]);
+
+  assert_eq!(stdout, "build\npush latest\ndeploy\n");
+  assert_eq!(status, Some(EXIT_SUCCESS));
+}
+
+#[test]
+fn module_recipes_listed() {
+  let tmp = tmptree! {
+    justfile: "mod docker\n\n# run tests\ntest:\n",
+    "docker.just": "# build image\nbuild tag:\n\n_private:\n",
+  };
+
+  let (status, stdout, _) = run(tmp.path(), &["--list"]);
+
+  assert_eq!(
+    stdout,
+    unindent(
+      "
+      Available recipes:
+          test              # run tests
+          docker::build tag # build image
+      "
+    )
+  );
+  assert_eq!(status, Some(EXIT_SUCCESS));
+
+  let (status, stdout, _) = run(tmp.path(), &["--summary"]);
+
+  assert_eq!(stdout, "test docker::build\n");
+  assert_eq!(status, Some(EXIT_SUCCESS));
+}
+
+#[test]
+fn module_recipe_show() {
+  let tmp = tmptree! {
+    justfile: "mod docker\n",
+    "docker.just": "build:\n  echo building\n",
+  };
+
+  let (status, stdout, _) = run(tmp.path(), &["--show", "docker::build"]);
+
+  assert_eq!(stdout, "build:\n    echo building\n");
+  assert_eq!(status, Some(EXIT_SUCCESS));
+}
+
+#[test]
+fn unknown_module_recipe() {
+  let tmp = tmptree! {
+    justfile: "mod docker\n\nfoo:\n",
+    "docker.just": "build:\n",
+  };
+
+  let (status, _, stderr) = run(tmp.path(), &["docker::push"]);
+
+  assert_eq!(
+    stderr,
+    "error: Justfile does not contain recipe `docker::push`.\n"
+  );
+  assert_eq!(status, Some(EXIT_FAILURE));
+}
+
+#[test]
+fn missing_module_source() {
+  let tmp = tmptree! {
+    justfile: "mod docker\n",
+  };
+
+  let (status, _, stderr) = run(tmp.path(), &[]);
+
+  assert_eq!(
+    stderr,
+    unindent(
+      "
+      error: Source file for module `docker` not found, expected `docker.just` or `docker/justfile`
+        |
+      1 | mod docker
+        |     ^^^^^^
+      "
+    )
+  );
+  assert_eq!(status, Some(EXIT_FAILURE));
+}
+
+#[test]
+fn unknown_module_dependency() {
+  let tmp = tmptree! {
+    justfile: "mod docker\n\nfoo: docker::push\n",
+    "docker.just": "build:\n",
+  };
+
+  let (status, _, stderr) = run(tmp.path(), &[]);
+
+  assert_eq!(
+    stderr,
+    unindent(
+      "
+      error: Recipe `foo` has unknown dependency `push`
+        |
+      3 | foo: docker::push
+        |              ^^^^
+      "
+    )
+  );
+  assert_eq!(status, Some(EXIT_FAILURE));
+}
+
+#[test]
+fn module_errors_report_file() {
+  let tmp = tmptree! {
+    justfile: "mod docker\n",
+    "docker.just": "build: push\n",
+  };
+
+  let (status, _, stderr) = run(tmp.path(), &[]);
+
+  assert_eq!(
+    stderr,
+    unindent(
+      "
+      error: Recipe `build` has unknown dependency `push`
+       --> docker.just:1:8
+        |
+      1 | build: push
+        |        ^^^^
+      "
+    )
+  );
+  assert_eq!(status, Some(EXIT_FAILURE));
+}
@@ -0,0 +1,103 @@
+test! {
+  name:     linewise,
+  justfile: r#"
+    set positional-arguments
+
+    foo bar baz:
+      echo $0
+      echo $1
+      echo $2
+      echo "$@"
+  "#,
+  args:     ("foo", "hello", "goodbye"),
+  stdout:   "
+    foo
+    hello
+    goodbye
+    hello goodbye
+  ",
+  stderr:   r#"
+    echo $0
+    echo $1
+    echo $2
+    echo "$@"
+  "#,
+}
+
+test! {
+  name:     variadic,
+  justfile: r#"
+    set positional-arguments
+
+    foo *bar:
+      @printf '[%s]\n' "$@"
+  "#,
+  args:     ("foo", "a b", "c"),
+  stdout:   "
+    [a b]
+    [c]
+  ",
+}
+
+test! {
+  name:     variadic_empty,
+  justfile: r#"
+    set positional-arguments
+
+    foo *bar:
+      @echo $#
+  "#,
+  stdout:   "0\n",
+}
+
+test! {
+  name:     default,
+  justfile: r#"
+    set positional-arguments
+
+    foo bar='baz':
+      @echo $1
+  "#,
+  stdout:   "baz\n",
+}
+
+test! {
+  name:     shebang,
+  justfile: "
+    set positional-arguments
+
+    foo bar:
+      #!/bin/sh
+      echo $1
+  ",
+  args:     ("foo", "hello"),
+  stdout:   "hello\n",
+}
+
+test! {
+  name:     shebang_variadic,
+  justfile: r#"
+    set positional-arguments
+
+    foo +bar:
+      #!/bin/sh
+      printf '[%s]\n' "$@"
+  "#,
+  args:     ("foo", "a b", "c d"),
+  stdout:   "
+    [a b]
+    [c d]
+  ",
+}
+
+test! {
+  name:     disabled,
+  justfile: r#"
+    set positional-arguments := false
+
+    foo bar:
+      @echo $#
+  "#,
+  args:     ("foo", "hello"),
+  stdout:   "0\n",
+}
@@ -0,0 +1,219 @@
+use crate::common::*;
+
+use std::time::{Duration, Instant};
+
+#[test]
+fn failed_line_is_retried() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [retry('3')]
+      foo:
+        @echo start
+        @echo x >> count && test $(cat count | wc -l) -ge 3
+        @echo done
+    ",
+  );
+
+  let (status, stdout, stderr) = run(tmp.path(), &[]);
+
+  assert_eq!(status, Some(EXIT_SUCCESS));
+  assert_eq!(stdout, "start\ndone\n");
+  assert_eq!(
+    stderr,
+    "===> Recipe `foo` failed on line 4 with exit code 1, retrying (1 of 3)...\n===> Recipe `foo` \
+     failed on line 4 with exit code 1, retrying (2 of 3)...\n",
+  );
+}
+
+#[test]
+fn retries_exhausted() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [retry('2')]
+      foo:
+        @exit 3
+    ",
+  );
+
+  let (status, _stdout, stderr) = run(tmp.path(), &[]);
+
+  assert_eq!(status, Some(3));
+  assert_eq!(
+    stderr,
+    "===> Recipe `foo` failed on line 3 with exit code 3, retrying (1 of 2)...\n===> Recipe `foo` \
+     failed on line 3 with exit code 3, retrying (2 of 2)...\nerror: Recipe `foo` failed on line \
+     3 with exit code 3\n",
+  );
+}
+
+#[test]
+fn zero_retries() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [retry('0')]
+      foo:
+        @exit 1
+    ",
+  );
+
+  let (status, _stdout, stderr) = run(tmp.path(), &[]);
+
+  assert_eq!(status, Some(EXIT_FAILURE));
+  assert_eq!(
+    stderr,
+    "error: Recipe `foo` failed on line 3 with exit code 1\n"
+  );
+}
+
+#[test]
+#[cfg(unix)]
+fn shebang_script_is_retried() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [retry('1')]
+      foo:
+        #!/usr/bin/env sh
+        echo attempt
+        echo x >> count
+        test $(cat count | wc -l) -ge 2
+    ",
+  );
+
+  let (status, stdout, stderr) = run(tmp.path(), &[]);
+
+  assert_eq!(status, Some(EXIT_SUCCESS));
+  assert_eq!(stdout, "attempt\nattempt\n");
+  assert_eq!(
+    stderr,
+    "===> Recipe `foo` failed with exit code 1, retrying (1 of 1)...\n",
+  );
+}
+
+#[test]
+fn backoff_doubles() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [retry('2', '200ms')]
+      foo:
+        @exit 1
+    ",
+  );
+
+  let start = Instant::now();
+
+  let (status, _stdout, _stderr) = run(tmp.path(), &[]);
+
+  assert_eq!(status, Some(EXIT_FAILURE));
+  assert!(start.elapsed() >= Duration::from_millis(600));
+}
+
+#[test]
+fn infallible_lines_are_not_retried() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [retry('3')]
+      foo:
+        -exit 1
+        @echo done
+    ",
+  );
+
+  let (status, stdout, stderr) = run(tmp.path(), &[]);
+
+  assert_eq!(status, Some(EXIT_SUCCESS));
+  assert_eq!(stdout, "done\n");
+  assert_eq!(stderr, "exit 1\n");
+}
+
+#[test]
+fn quiet() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [retry('1')]
+      foo:
+        exit 1
+    ",
+  );
+
+  let (status, _stdout, stderr) = run(tmp.path(), &["--quiet"]);
+
+  assert_eq!(status, Some(EXIT_FAILURE));
+  assert_eq!(stderr, "");
+}
+
+#[test]
+fn backoff_stops_when_sibling_fails() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [retry('3', '4s')]
+      foo:
+        @exit 1
+
+      bar:
+        @sleep 0.2 && exit 2
+    ",
+  );
+
+  let start = Instant::now();
+
+  let (status, _stdout, stderr) = run(tmp.path(), &["--jobs", "2", "foo", "bar"]);
+
+  assert!(start.elapsed() < Duration::from_secs(2), "{}", stderr);
+  assert_eq!(status, Some(2));
+  assert_eq!(
+    stderr,
+    "===> Recipe `foo` failed on line 3 with exit code 1, retrying (1 of 3)...\nerror: Recipe \
+     `bar` failed on line 6 with exit code 2\n",
+  );
+}
+
+#[test]
+fn backoff_stops_at_timeout() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [retry('3', '4s')]
+      [timeout('200ms')]
+      foo:
+        @exit 1
+    ",
+  );
+
+  let start = Instant::now();
+
+  let (status, _stdout, stderr) = run(tmp.path(), &[]);
+
+  assert!(start.elapsed() < Duration::from_secs(2), "{}", stderr);
+  assert_eq!(status, Some(EXIT_FAILURE));
+  assert!(
+    stderr.contains("error: Recipe `foo` timed out on line 4 after "),
+    "{}",
+    stderr
+  );
+}
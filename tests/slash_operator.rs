@@ -0,0 +1,99 @@
+use crate::common::*;
+
+test! {
+  name:     once,
+  justfile: "
+    a := 'foo' / 'bar'
+
+    foo:
+      @echo {{a}}
+  ",
+  stdout:   "foo/bar\n",
+}
+
+test! {
+  name:     twice,
+  justfile: "
+    a := 'foo' / 'bar' / 'baz'
+
+    foo:
+      @echo {{a}}
+  ",
+  stdout:   "foo/bar/baz\n",
+}
+
+test! {
+  name:     no_duplicate_separators,
+  justfile: "
+    a := 'foo/' / '/bar/' / 'baz'
+
+    foo:
+      @echo {{a}}
+  ",
+  stdout:   "foo/bar/baz\n",
+}
+
+test! {
+  name:     prefix,
+  justfile: "
+    a := / 'usr' / 'bin'
+
+    foo:
+      @echo {{a}}
+  ",
+  stdout:   "/usr/bin\n",
+}
+
+test! {
+  name:     prefix_no_duplicate_separator,
+  justfile: "
+    a := / '/usr'
+
+    foo:
+      @echo {{a}}
+  ",
+  stdout:   "/usr\n",
+}
+
+test! {
+  name:     same_precedence_as_concatenation,
+  justfile: "
+    a := 'foo' / 'bar' + 'baz'
+
+    foo:
+      @echo {{a}}
+  ",
+  stdout:   "foo/barbaz\n",
+}
+
+test! {
+  name:     in_interpolation,
+  justfile: "
+    dir := 'build'
+
+    foo:
+      echo {{dir / 'out'}}
+  ",
+  stdout:   "build/out\n",
+  stderr:   "echo build/out\n",
+}
+
+test! {
+  name:     dump,
+  justfile: r#"a := / 'usr' / dir + 'bin'
+dir := 'local'"#,
+  args:     ("--dump"),
+  stdout:   "a := / 'usr' / dir + 'bin'\n\ndir := 'local'\n",
+}
+
+test! {
+  name:     missing_rhs,
+  justfile: "a := 'foo' / ,",
+  stderr:   "
+    error: Expected backtick, format string start, identifier, '(', '/', cooked string, or raw string, but found ','
+      |
+    1 | a := 'foo' / ,
+      |              ^
+  ",
+  status:   EXIT_FAILURE,
+}
@@ -0,0 +1,129 @@
+use crate::common::*;
+
+test! {
+  name:     indented_raw_string,
+  justfile: "
+    x := '''
+      foo
+        bar
+      baz
+    '''
+
+    a:
+      @printf '%s' '{{x}}'
+  ",
+  stdout:   "foo\n  bar\nbaz\n",
+}
+
+test! {
+  name:     indented_cooked_string,
+  justfile: r#"
+    x := """
+      foo\tbar
+      \"baz\"
+    """
+
+    a:
+      @printf '%s' '{{x}}'
+  "#,
+  stdout:   "foo\tbar\n\"baz\"\n",
+}
+
+test! {
+  name:     indented_string_without_leading_newline,
+  justfile: "
+    x := '''foo
+      bar'''
+
+    a:
+      @printf '%s' '{{x}}'
+  ",
+  stdout:   "foo\n  bar",
+}
+
+test! {
+  name:     indented_string_blank_lines,
+  justfile: "
+    x := '''
+      foo
+
+      bar
+    '''
+
+    a:
+      @printf '%s' '{{x}}'
+  ",
+  stdout:   "foo\n\nbar\n",
+}
+
+test! {
+  name:     indented_cooked_string_escaped_newline_not_stripped,
+  justfile: r#"
+    x := """\n  foo"""
+
+    a:
+      @printf '%s' '[{{x}}]'
+  "#,
+  stdout:   "[\n  foo]",
+}
+
+test! {
+  name:     indented_string_dump,
+  justfile: "
+    x := '''
+      foo
+    '''
+  ",
+  args:     ("--dump"),
+  stdout:   "x := '''\n  foo\n'''\n",
+}
+
+test! {
+  name:     error_after_indented_string,
+  justfile: "
+    x := '''
+      foo
+    '''
+
+    a: b
+  ",
+  stderr:   "
+    error: Recipe `a` has unknown dependency `b`
+      |
+    5 | a: b
+      |    ^
+  ",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     invalid_escape_in_indented_string,
+  justfile: r#"
+    x := """
+      foo
+      b\qr
+    """
+  "#,
+  stderr:   r#"
+    error: `\q` is not a valid escape sequence
+      |
+    3 |   b\qr
+      |    ^^
+  "#,
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     unterminated_indented_string,
+  justfile: "
+    x := '''
+      foo
+  ",
+  stderr:   "
+    error: Unterminated string
+      |
+    1 | x := '''
+      |      ^
+  ",
+  status:   EXIT_FAILURE,
+}
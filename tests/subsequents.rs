@@ -0,0 +1,158 @@
+use crate::common::*;
+
+test! {
+  name:     success,
+  justfile: "
+    foo: && bar
+      echo foo
+
+    bar:
+      echo bar
+  ",
+  stdout:   "
+    foo
+    bar
+  ",
+  stderr:   "
+    echo foo
+    echo bar
+  ",
+}
+
+test! {
+  name:     failure,
+  justfile: "
+    foo: && bar
+      echo foo
+      false
+
+    bar:
+      echo bar
+  ",
+  stdout:   "
+    foo
+  ",
+  stderr:   "
+    echo foo
+    false
+    error: Recipe `foo` failed on line 3 with exit code 1
+  ",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     priors_and_subsequents,
+  justfile: "
+    foo: bar && baz
+      @echo foo
+
+    bar:
+      @echo bar
+
+    baz:
+      @echo baz
+  ",
+  stdout:   "
+    bar
+    foo
+    baz
+  ",
+}
+
+test! {
+  name:     arguments,
+  justfile: "
+    x := 'a'
+
+    foo y: && (bar x) (bar y + 'c')
+      @echo foo
+
+    bar z:
+      @echo bar {{z}}
+  ",
+  args:     ("foo", "b"),
+  stdout:   "
+    foo
+    bar a
+    bar bc
+  ",
+}
+
+test! {
+  name:     deduplicated,
+  justfile: "
+    foo: bar && bar baz
+
+    bar:
+      @echo bar
+
+    baz: && bar
+      @echo baz
+  ",
+  stdout:   "
+    bar
+    baz
+  ",
+}
+
+test! {
+  name:     circular_through_dependency,
+  justfile: "
+    foo: && bar
+      @echo foo
+
+    bar: foo
+      @echo bar
+  ",
+  stderr:   "
+    error: Recipe `foo` has circular dependency `bar -> foo -> bar`
+      |
+    1 | foo: && bar
+      |         ^^^
+  ",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     circular,
+  justfile: "
+    foo: && foo
+  ",
+  stderr:   "
+    error: Recipe `foo` depends on itself
+      |
+    1 | foo: && foo
+      |         ^^^
+  ",
+  status:   EXIT_FAILURE,
+}
+
+test! {
+  name:     show,
+  justfile: "
+    foo: bar && (baz 'a') qux
+      echo foo
+
+    bar:
+    baz x:
+    qux:
+  ",
+  args:     ("--show", "foo"),
+  stdout:   "
+    foo: bar && (baz 'a') qux
+        echo foo
+  ",
+}
+
+test! {
+  name:     show_subsequents_only,
+  justfile: "
+    foo: && bar
+
+    bar:
+  ",
+  args:     ("--show", "foo"),
+  stdout:   "
+    foo: && bar
+  ",
+}
@@ -0,0 +1,365 @@
+use crate::common::*;
+
+use regex::Regex;
+use std::{
+  process::ExitStatus,
+  thread,
+  time::{Duration, Instant},
+};
+
+fn assert_stderr_matches(stderr: &str, pattern: &str) {
+  let regex = Regex::new(&format!("^{}$", pattern)).unwrap();
+  assert!(
+    regex.is_match(stderr),
+    "stderr {:?} did not match {:?}",
+    stderr,
+    pattern
+  );
+}
+
+#[test]
+fn attribute() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [timeout('200ms')]
+      foo:
+        @echo start
+        @sleep 5
+        @echo end
+    ",
+  );
+
+  let start = Instant::now();
+
+  let (status, stdout, stderr) = run(tmp.path(), &[]);
+
+  assert!(start.elapsed() < Duration::from_secs(4));
+  assert_eq!(status, Some(EXIT_FAILURE));
+  assert_eq!(stdout, "start\n");
+  assert_stderr_matches(
+    &stderr,
+    r"error: Recipe `foo` timed out on line 4 after \d+\.\ds\n",
+  );
+}
+
+#[test]
+fn flag() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      foo:
+        @sleep 5
+    ",
+  );
+
+  let (status, _stdout, stderr) = run(tmp.path(), &["--timeout", "200ms"]);
+
+  assert_eq!(status, Some(EXIT_FAILURE));
+  assert_stderr_matches(
+    &stderr,
+    r"error: Recipe `foo` timed out on line 2 after \d+\.\ds\n",
+  );
+}
+
+#[test]
+fn flag_applies_to_each_recipe() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      foo: bar
+        @sleep 0.5
+
+      bar:
+        @sleep 0.5
+    ",
+  );
+
+  let (status, _stdout, stderr) = run(tmp.path(), &["--timeout", "800ms"]);
+
+  assert_eq!(status, Some(EXIT_SUCCESS), "{}", stderr);
+}
+
+#[test]
+fn attribute_overrides_flag() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [timeout('10s')]
+      foo:
+        @sleep 0.5
+        @echo done
+    ",
+  );
+
+  let (status, stdout, stderr) = run(tmp.path(), &["--timeout", "100ms"]);
+
+  assert_eq!(status, Some(EXIT_SUCCESS), "{}", stderr);
+  assert_eq!(stdout, "done\n");
+}
+
+#[test]
+fn timeout_covers_whole_recipe() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [timeout('600ms')]
+      foo:
+        @sleep 0.4
+        @sleep 0.4
+        @echo done
+    ",
+  );
+
+  let (status, stdout, stderr) = run(tmp.path(), &[]);
+
+  assert_eq!(status, Some(EXIT_FAILURE));
+  assert_eq!(stdout, "");
+  assert_stderr_matches(
+    &stderr,
+    r"error: Recipe `foo` timed out on line 4 after \d+\.\ds\n",
+  );
+}
+
+#[test]
+#[cfg(unix)]
+fn shebang() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [timeout('200ms')]
+      foo:
+        #!/usr/bin/env sh
+        sleep 5
+    ",
+  );
+
+  let (status, _stdout, stderr) = run(tmp.path(), &[]);
+
+  assert_eq!(status, Some(EXIT_FAILURE));
+  assert_stderr_matches(&stderr, r"error: Recipe `foo` timed out after \d+\.\ds\n");
+}
+
+#[test]
+#[cfg(unix)]
+fn process_group_is_killed() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [timeout('200ms')]
+      foo:
+        @(sleep 1 && touch late) & wait
+    ",
+  );
+
+  let (status, _stdout, _stderr) = run(tmp.path(), &[]);
+
+  assert_eq!(status, Some(EXIT_FAILURE));
+
+  thread::sleep(Duration::from_millis(1500));
+
+  assert!(!tmp.path().join("late").exists());
+}
+
+#[test]
+fn dry_run() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [timeout('100ms')]
+      foo:
+        sleep 5
+    ",
+  );
+
+  let (status, _stdout, stderr) = run(tmp.path(), &["--dry-run"]);
+
+  assert_eq!(status, Some(EXIT_SUCCESS));
+  assert_eq!(stderr, "sleep 5\n");
+}
+
+#[test]
+fn piped_stdin() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [timeout('5s')]
+      foo:
+        @read x && echo got $x
+    ",
+  );
+
+  let mut child = Command::new(executable_path("just"))
+    .current_dir(tmp.path())
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .expect("just invocation failed");
+
+  child.stdin.take().unwrap().write_all(b"hello\n").unwrap();
+
+  let output = child.wait_with_output().unwrap();
+
+  assert!(output.status.success());
+  assert_eq!(str::from_utf8(&output.stdout).unwrap(), "got hello\n");
+}
+
+/// Run `just` in `path`, with a terminal as its controlling terminal and its
+/// stdin and stdout, writing `input` to the terminal after `delay`. Returns
+/// `just`'s exit status and everything written to the terminal.
+#[cfg(unix)]
+fn run_in_terminal(path: &Path, input: &[u8], delay: Duration) -> (ExitStatus, String) {
+  use std::{
+    fs::File,
+    io::{self, Read},
+    os::unix::{io::FromRawFd, process::CommandExt},
+    ptr,
+  };
+
+  let (mut master, slave) = unsafe {
+    let mut master = 0;
+    let mut slave = 0;
+    assert_eq!(
+      libc::openpty(
+        &mut master,
+        &mut slave,
+        ptr::null_mut(),
+        ptr::null_mut(),
+        ptr::null_mut(),
+      ),
+      0
+    );
+    (File::from_raw_fd(master), File::from_raw_fd(slave))
+  };
+
+  let mut command = Command::new(executable_path("just"));
+
+  command
+    .current_dir(path)
+    .stdin(slave.try_clone().unwrap())
+    .stdout(slave.try_clone().unwrap())
+    .stderr(Stdio::null());
+
+  // Make the terminal just's controlling terminal, with just in the
+  // foreground process group
+  unsafe {
+    command.pre_exec(|| {
+      if libc::setsid() == -1 || libc::ioctl(0, libc::TIOCSCTTY as _, 0) == -1 {
+        return Err(io::Error::last_os_error());
+      }
+      Ok(())
+    });
+  }
+
+  let mut child = command.spawn().expect("just invocation failed");
+
+  drop(command);
+  drop(slave);
+
+  thread::sleep(delay);
+
+  master.write_all(input).unwrap();
+
+  let status = child.wait().unwrap();
+
+  // Reading fails once just and its children have closed the terminal
+  let mut output = Vec::new();
+  let mut buffer = [0; 1024];
+  while let Ok(n) = master.read(&mut buffer) {
+    if n == 0 {
+      break;
+    }
+    output.extend_from_slice(&buffer[..n]);
+  }
+
+  (status, String::from_utf8_lossy(&output).into_owned())
+}
+
+#[test]
+#[cfg(unix)]
+fn terminal_stdin() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [timeout('5s')]
+      foo:
+        @read x && echo got $x
+    ",
+  );
+
+  let start = Instant::now();
+
+  let (status, output) = run_in_terminal(tmp.path(), b"hello\n", Duration::from_millis(0));
+
+  assert!(start.elapsed() < Duration::from_secs(4));
+  assert!(status.success());
+  assert!(output.contains("got hello"));
+}
+
+#[test]
+#[cfg(unix)]
+fn terminal_stdin_process_group_is_killed() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [timeout('200ms')]
+      foo:
+        @(trap '' HUP; sleep 1 && touch late) & wait
+    ",
+  );
+
+  let (status, _output) = run_in_terminal(tmp.path(), b"", Duration::from_millis(0));
+
+  assert_eq!(status.code(), Some(EXIT_FAILURE));
+
+  // Processes left in the terminal's foreground process group are sent
+  // `SIGHUP` when just exits, which `late` ignores
+
+  thread::sleep(Duration::from_millis(1500));
+
+  assert!(!tmp.path().join("late").exists());
+}
+
+#[test]
+#[cfg(unix)]
+fn terminal_stdin_interrupt() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [timeout('5s')]
+      foo:
+        @sleep 5
+    ",
+  );
+
+  let start = Instant::now();
+
+  let (status, _output) = run_in_terminal(tmp.path(), b"\x03", Duration::from_millis(500));
+
+  assert!(start.elapsed() < Duration::from_secs(4));
+  assert_eq!(status.code(), Some(130));
+}
@@ -0,0 +1,283 @@
+use crate::common::*;
+
+/// Set the modification time of `path` to the start of 2000, so that it is
+/// older than any file created by a test
+fn age(path: &Path) {
+  let status = Command::new("touch")
+    .args(&["-t", "200001010000"])
+    .arg(path)
+    .status()
+    .unwrap();
+
+  assert!(status.success());
+}
+
+#[test]
+fn missing_output() {
+  let tmp = tempdir();
+
+  justfile(
+    tmp.path(),
+    "
+      [outputs('out')]
+      build:
+        @echo build
+        @touch out
+    ",
+  );
+
+  assert_eq!(run_success(tmp.path(), &[]).0, "build\n");
+  assert_eq!(run_success(tmp.path(), &[]).0, "");
+}
+
+#[test]
+fn newer_input() {
+  let tmp = tmptree! {
+    input: "",
+    out: "",
+  };
+
+  justfile(
+    tmp.path(),
+    "
+      [inputs('input')]
+      [outputs('out')]
+      build:
+        @echo build
+        @touch out
+    ",
+  );
+
+  age(&tmp.path().join("out"));
+
+  assert_eq!(run_success(tmp.path(), &[]).0, "build\n");
+  assert_eq!(run_success(tmp.path(), &[]).0, "");
+}
+
+#[test]
+fn older_input() {
+  let tmp = tmptree! {
+    input: "",
+    out: "",
+  };
+
+  justfile(
+    tmp.path(),
+    "
+      [inputs('input')]
+      [outputs('out')]
+      build:
+        @echo build
+    ",
+  );
+
+  age(&tmp.path().join("input"));
+
+  assert_eq!(run_success(tmp.path(), &[]).0, "");
+}
+
+#[test]
+fn oldest_output_is_compared() {
+  let tmp = tmptree! {
+    input: "",
+    a: "",
+    b: "",
+  };
+
+  justfile(
+    tmp.path(),
+    "
+      [inputs('input')]
+      [outputs('a', 'b')]
+      build:
+        @echo build
+    ",
+  );
+
+  age(&tmp.path().join("b"));
+
+  assert_eq!(run_success(tmp.path(), &[]).0, "build\n");
+}
+
+#[test]
+fn glob_inputs() {
+  let tmp = tmptree! {
+    src: {
+      "a.c": "",
+      "b.c": "",
+      "b.h": "",
+    },
+    main: "",
+  };
+
+  justfile(
+    tmp.path(),
+    "
+      [inputs('src/*.c')]
+      [outputs('main')]
+      build:
+        @echo build
+    ",
+  );
+
+  age(&tmp.path().join("src/a.c"));
+  age(&tmp.path().join("src/b.c"));
+
+  assert_eq!(run_success(tmp.path(), &[]).0, "");
+
+  age(&tmp.path().join("main"));
+  fs::write(tmp.path().join("src/a.c"), "").unwrap();
+
+  assert_eq!(run_success(tmp.path(), &[]).0, "build\n");
+}
+
+#[test]
+fn recipes_without_outputs_always_run() {
+  let tmp = tmptree! {
+    input: "",
+  };
+
+  justfile(
+    tmp.path(),
+    "
+      [inputs('input')]
+      build:
+        @echo build
+    ",
+  );
+
+  age(&tmp.path().join("input"));
+
+  assert_eq!(run_success(tmp.path(), &[]).0, "build\n");
+}
+
+#[test]
+fn dependency_that_ran_invalidates_dependent() {
+  let tmp = tmptree! {
+    out: "",
+  };
+
+  justfile(
+    tmp.path(),
+    "
+      [outputs('out')]
+      build: generate
+        @echo build
+
+      generate:
+        @echo generate
+    ",
+  );
+
+  assert_eq!(run_success(tmp.path(), &[]).0, "generate\nbuild\n");
+}
+
+#[test]
+fn up_to_date_dependency_does_not_invalidate_dependent() {
+  let tmp = tmptree! {
+    generated: "",
+    out: "",
+  };
+
+  justfile(
+    tmp.path(),
+    "
+      [outputs('out')]
+      build: generate
+        @echo build
+
+      [outputs('generated')]
+      generate:
+        @echo generate
+    ",
+  );
+
+  assert_eq!(run_success(tmp.path(), &[]).0, "");
+}
+
+#[test]
+fn working_directory_attribute() {
+  let tmp = tmptree! {
+    sub: {
+      input: "",
+      out: "",
+    },
+  };
+
+  justfile(
+    tmp.path(),
+    "
+      [working-directory('sub')]
+      [inputs('input')]
+      [outputs('out')]
+      build:
+        @echo build
+    ",
+  );
+
+  age(&tmp.path().join("sub/input"));
+
+  assert_eq!(run_success(tmp.path(), &[]).0, "");
+
+  fs::write(tmp.path().join("sub/input"), "").unwrap();
+
+  assert_eq!(run_success(tmp.path(), &[]).0, "build\n");
+}
+
+#[test]
+fn verbose() {
+  let tmp = tmptree! {
+    out: "",
+  };
+
+  justfile(
+    tmp.path(),
+    "
+      [outputs('out')]
+      build:
+        @echo build
+    ",
+  );
+
+  let (stdout, stderr) = run_success(tmp.path(), &["--verbose"]);
+
+  assert_eq!(stdout, "");
+  assert!(
+    stderr.contains("===> Recipe `build` is up to date\n"),
+    "unexpected stderr: {}",
+    stderr
+  );
+  assert!(!stderr.contains("Running recipe"));
+}
+
+#[test]
+fn jobs() {
+  let tmp = tmptree! {
+    a: "",
+    b: "",
+    out: "",
+  };
+
+  justfile(
+    tmp.path(),
+    "
+      [outputs('out')]
+      build: a b
+        @echo build
+
+      [outputs('a')]
+      a:
+        @touch a
+
+      [outputs('b')]
+      b:
+        @touch b
+    ",
+  );
+
+  assert_eq!(run_success(tmp.path(), &["--jobs", "2"]).0, "");
+
+  fs::remove_file(tmp.path().join("b")).unwrap();
+
+  assert_eq!(run_success(tmp.path(), &["--jobs", "2"]).0, "build\n");
+}
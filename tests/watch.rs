@@ -0,0 +1,214 @@
+#[cfg(unix)]
+mod unix {
+  use executable_path::executable_path;
+  use std::{
+    fs,
+    path::Path,
+    process::{Child, Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+  };
+  use tempfile::TempDir;
+  use test_utilities::{tempdir, unindent};
+
+  fn watch(justfile: &str) -> (TempDir, Child) {
+    let tmp = tempdir();
+
+    fs::write(tmp.path().join("justfile"), unindent(justfile)).unwrap();
+    fs::write(tmp.path().join(".gitignore"), "log\n").unwrap();
+    fs::write(tmp.path().join("a"), "1\n").unwrap();
+    fs::write(tmp.path().join("b"), "1\n").unwrap();
+
+    let child = Command::new(&executable_path("just"))
+      .current_dir(&tmp)
+      .arg("--watch")
+      .stderr(Stdio::null())
+      .spawn()
+      .expect("just invocation failed");
+
+    (tmp, child)
+  }
+
+  fn log(tmp: &TempDir) -> String {
+    fs::read_to_string(tmp.path().join("log")).unwrap_or_default()
+  }
+
+  fn wait_for_log(tmp: &TempDir, expected: &str) {
+    let start = Instant::now();
+
+    while log(tmp) != expected {
+      if start.elapsed() > Duration::from_secs(10) {
+        panic!("log was {:?}, expected {:?}", log(tmp), expected);
+      }
+      thread::sleep(Duration::from_millis(50));
+    }
+  }
+
+  /// Write `contents` to `path`, after giving `just` time to finish ignoring
+  /// changes made while recipes were running
+  fn write(path: &Path, contents: &str) {
+    thread::sleep(Duration::from_millis(500));
+    fs::write(path, contents).unwrap();
+  }
+
+  fn interrupt(mut child: Child) {
+    unsafe {
+      libc::kill(child.id() as i32, libc::SIGINT);
+    }
+
+    assert_eq!(child.wait().unwrap().code(), Some(130));
+  }
+
+  #[test]
+  fn runs_again_when_file_changes() {
+    let (tmp, child) = watch(
+      "
+        default:
+          cat a >> log
+      ",
+    );
+
+    wait_for_log(&tmp, "1\n");
+
+    write(&tmp.path().join("a"), "2\n");
+
+    wait_for_log(&tmp, "1\n2\n");
+
+    interrupt(child);
+  }
+
+  #[test]
+  fn reloads_justfile() {
+    let (tmp, child) = watch(
+      "
+        default:
+          echo old >> log
+      ",
+    );
+
+    wait_for_log(&tmp, "old\n");
+
+    write(
+      &tmp.path().join("justfile"),
+      "default:\n  echo new >> log\n",
+    );
+
+    wait_for_log(&tmp, "old\nnew\n");
+
+    interrupt(child);
+  }
+
+  #[test]
+  fn ignored_files_are_not_watched() {
+    let (tmp, child) = watch(
+      "
+        default:
+          cat a >> log
+      ",
+    );
+
+    wait_for_log(&tmp, "1\n");
+
+    thread::sleep(Duration::from_millis(500));
+
+    assert_eq!(log(&tmp), "1\n");
+
+    interrupt(child);
+  }
+
+  #[test]
+  fn watch_attribute_restricts_watched_files() {
+    let (tmp, child) = watch(
+      "
+        [watch('b')]
+        default:
+          cat a >> log
+      ",
+    );
+
+    wait_for_log(&tmp, "1\n");
+
+    write(&tmp.path().join("a"), "2\n");
+
+    thread::sleep(Duration::from_millis(500));
+
+    assert_eq!(log(&tmp), "1\n");
+
+    write(&tmp.path().join("b"), "2\n");
+
+    wait_for_log(&tmp, "1\n2\n");
+
+    interrupt(child);
+  }
+
+  #[test]
+  fn failing_recipe_is_run_again() {
+    let (tmp, child) = watch(
+      "
+        default:
+          cat a >> log
+          exit 1
+      ",
+    );
+
+    wait_for_log(&tmp, "1\n");
+
+    write(&tmp.path().join("a"), "2\n");
+
+    wait_for_log(&tmp, "1\n2\n");
+
+    interrupt(child);
+  }
+
+  #[test]
+  fn files_written_by_recipe_are_not_watched() {
+    let (tmp, child) = watch(
+      "
+        default:
+          cat a >> log
+          echo run >> out
+          sleep 0.2
+      ",
+    );
+
+    wait_for_log(&tmp, "1\n");
+
+    thread::sleep(Duration::from_millis(1000));
+
+    assert_eq!(log(&tmp), "1\n");
+
+    write(&tmp.path().join("a"), "2\n");
+
+    wait_for_log(&tmp, "1\n2\n");
+
+    interrupt(child);
+  }
+
+  #[test]
+  fn changes_while_recipe_is_running_are_ignored() {
+    let (tmp, child) = watch(
+      "
+        default:
+          cat a >> log
+          sleep 2
+          echo done >> log
+      ",
+    );
+
+    wait_for_log(&tmp, "1\n");
+
+    write(&tmp.path().join("a"), "2\n");
+
+    wait_for_log(&tmp, "1\ndone\n");
+
+    thread::sleep(Duration::from_millis(500));
+
+    assert_eq!(log(&tmp), "1\ndone\n");
+
+    write(&tmp.path().join("a"), "3\n");
+
+    wait_for_log(&tmp, "1\ndone\n3\ndone\n");
+
+    interrupt(child);
+  }
+}
@@ -184,3 +184,143 @@ fn search_dir_parent() -> Result<(), Box<dyn Error>> {
 
   Ok(())
 }
+
+/// Test that the `working-directory` setting applies to linewise recipes,
+/// shebang recipes, and backticks
+#[test]
+fn working_directory_setting() -> Result<(), Box<dyn Error>> {
+  let tmp = tmptree! {
+    justfile: "
+set working-directory := 'child'
+
+foo := `cat data`
+
+linewise bar=`cat data`: shebang
+  echo expression: {{foo}}
+  echo default: {{bar}}
+  echo linewise: `cat data`
+
+shebang:
+  #!/usr/bin/env sh
+  echo \"shebang:\" `cat data`
+",
+    data: "BAD",
+    child: {
+      data: DATA,
+    },
+  };
+
+  let output = Command::new(executable_path("just"))
+    .current_dir(&tmp.path())
+    .output()?;
+
+  if !output.status.success() {
+    eprintln!("{:?}", String::from_utf8_lossy(&output.stderr));
+    panic!();
+  }
+
+  let stdout = String::from_utf8(output.stdout).unwrap();
+
+  assert_eq!(stdout, WANT);
+
+  Ok(())
+}
+
+/// Test that the `working-directory` attribute is relative to the
+/// `working-directory` setting, and that `no-cd` ignores the setting
+#[test]
+fn working_directory_setting_and_attributes() -> Result<(), Box<dyn Error>> {
+  let tmp = tmptree! {
+    justfile: "
+set working-directory := 'a'
+
+default: setting attribute no-cd
+
+setting:
+  @cat data
+
+[working-directory('b')]
+attribute:
+  @cat data
+
+[no-cd]
+no-cd:
+  @cat data
+",
+    data: "root\n",
+    a: {
+      data: "a\n",
+      b: {
+        data: "b\n",
+      },
+    },
+  };
+
+  let output = Command::new(executable_path("just"))
+    .current_dir(&tmp.path())
+    .output()?;
+
+  if !output.status.success() {
+    eprintln!("{:?}", String::from_utf8_lossy(&output.stderr));
+    panic!();
+  }
+
+  let stdout = String::from_utf8(output.stdout).unwrap();
+
+  assert_eq!(stdout, "a\nb\nroot\n");
+
+  Ok(())
+}
+
+/// Test that `absolute_path` and `sha256_file` resolve relative paths against
+/// the working directory of the recipe that calls them
+#[test]
+fn path_functions_use_recipe_working_directory() -> Result<(), Box<dyn Error>> {
+  let tmp = tmptree! {
+    justfile: "
+set working-directory := 'a'
+
+default: setting attribute no-cd
+
+setting:
+  @test {{absolute_path('data')}} = $PWD/data
+  @echo {{sha256_file('data')}}
+
+[working-directory('b')]
+attribute:
+  @test {{absolute_path('data')}} = $PWD/data
+  @echo {{sha256_file('data')}}
+
+[no-cd]
+no-cd:
+  @test {{absolute_path('data')}} = $PWD/data
+  @echo {{sha256_file('data')}}
+",
+    data: "root\n",
+    a: {
+      data: "a\n",
+      b: {
+        data: "b\n",
+      },
+    },
+  };
+
+  let output = Command::new(executable_path("just"))
+    .current_dir(&tmp.path())
+    .output()?;
+
+  if !output.status.success() {
+    eprintln!("{:?}", String::from_utf8_lossy(&output.stderr));
+    panic!();
+  }
+
+  let stdout = String::from_utf8(output.stdout).unwrap();
+
+  assert_eq!(stdout.lines().collect::<Vec<&str>>(), [
+    "87428fc522803d31065e7bce3cf03fe475096631e5e07bbd7a0fde60c4cf25c7",
+    "0263829989b6fd954f72baaf2fc64bc2e2f01d692d4de72986ea808f6e99813f",
+    "53175bcc0524f37b47062fafdda28e3f8eb91d519ca0a184ca71bbebe72f969a",
+  ]);
+
+  Ok(())
+}